@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::errors::{AoCError, AoCResult};
+
+/// Known answers of one year, stored as 'answers/year_YYYY.toml':
+/// ```toml
+/// [day_01]
+/// part_1 = "138"
+/// part_2 = "1771"
+/// ```
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Answers {
+    pub fn load(year: u16) -> AoCResult<Self> {
+        Self::load_from_file(&get_path(year))
+    }
+
+    /// Loads the answers from the given file. A missing file results in an empty store.
+    pub fn load_from_file(path: &Path) -> AoCResult<Self> {
+        let days = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|e| AoCError::BadInputFormat(format!(
+                    "Parsing answers file '{}' failed: {}", path.display(), e)))?,
+            Err(_) if !path.exists() => BTreeMap::new(),
+            Err(e) => return Err(AoCError::IOError(format!(
+                "Reading answers file '{}' failed: {}", path.display(), e))),
+        };
+        Ok(Self { path: path.to_path_buf(), days })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days.get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        self.days.entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), answer);
+    }
+
+    /// Compares the given answer to the known answer of this day and part.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Missing,
+        }
+    }
+
+    pub fn save(&self) -> AoCResult<()> {
+        let content = toml::to_string(&self.days)
+            .map_err(|e| AoCError::IOError(format!("Serializing answers failed: {}", e)))?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| AoCError::IOError(format!(
+                    "Creating folder structure '{:?}' failed. {}", parent, e)))?;
+        }
+        fs::write(&self.path, content)
+            .map_err(|e| AoCError::IOError(format!(
+                "Writing to '{}' failed: {}", self.path.display(), e)))
+    }
+}
+
+/// Returns the folder of the answer files, independent of the current directory.
+pub(crate) fn get_answers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers")
+}
+
+fn get_path(year: u16) -> PathBuf {
+    get_answers_dir().join(format!("year_{}.toml", year))
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+fn part_key(part: u8) -> String {
+    format!("part_{}", part)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_answers_roundtrip() -> AoCResult<()> {
+        let path = std::env::temp_dir().join("aoc_answers_check_answers_roundtrip.toml");
+        let _ = fs::remove_file(&path);
+        let mut answers = Answers::load_from_file(&path)?;
+        assert_eq!(answers.check(1, 1, "138"), Verdict::Missing);
+        answers.set(1, 1, "138".to_string());
+        answers.set(8, 2, "#..#\n.##.\n".to_string());
+        answers.save()?;
+
+        let answers = Answers::load_from_file(&path)?;
+        assert_eq!(answers.check(1, 1, "138"), Verdict::Pass);
+        assert_eq!(answers.check(1, 1, "139"), Verdict::Fail("138".to_string()));
+        assert_eq!(answers.get(8, 2), Some("#..#\n.##.\n"));
+        assert_eq!(answers.get(8, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_known_answers_parse() -> AoCResult<()> {
+        for year in [2015, 2016, 2017, 2023, 2024] {
+            let answers = Answers::load(year)?;
+            assert!(answers.get(1, 1).is_some());
+        }
+        Ok(())
+    }
+}
@@ -1,810 +1,388 @@
-use advent_of_code::{year_2015, year_2016, year_2017, year_2023, year_2024};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use advent_of_code::answers::{Answers, Verdict};
 use advent_of_code::errors::AoCError;
-use advent_of_code::input::get_input;
-
-
-#[allow(dead_code)]
-fn run_2015() -> Result<(), AoCError<String>> {
-    use year_2015::*;
-    let year = 2015;
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
-
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
-
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
-
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
-
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
-
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
-
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
-
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
-
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
-
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
-
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
-
-    Ok(())
+use advent_of_code::input::InputConfig;
+use advent_of_code::{runner, scaffold, solution};
+use advent_of_code::runner::{format_duration, InputSource, Outcome, ProfileComparison, Report,
+                             Summary};
+use advent_of_code::solution::Solution;
+use advent_of_code::submit::{History, HttpSubmitter, Response};
+
+#[derive(Parser)]
+#[command(version, about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
 }
 
-#[allow(dead_code)]
-fn run_2016() -> Result<(), AoCError<String>> {
-    use year_2016::*;
-    let year = 2016;
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-    
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-    
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
-
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
-
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
-
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
-
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
-
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
-
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
-
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
-
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
-
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
-
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
-
-    Ok(())
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the selected solutions on their puzzle input
+    Run(RunArgs),
+    /// Runs the selected solutions and compares the results to the known answers
+    Verify(VerifyArgs),
+    /// Runs the selected solutions on the inputs of several profiles and compares the results
+    Compare(CompareArgs),
+    /// Creates a new day from the template of its year and registers it
+    New(NewArgs),
+    /// Runs a solution and submits its answer, unless it is known to be wrong
+    Submit(SubmitArgs),
 }
 
-#[allow(dead_code)]
-fn run_2017() -> Result<(), AoCError<String>> {
-    use year_2017::*;
-    let year = 2017;
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
-
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
-
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
-
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
-
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
-
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
-
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
-
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
-/*
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
-
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
-
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
-*/
-    Ok(())
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["all", "year"])))]
+struct Selection {
+    /// Select the solutions of all years
+    #[arg(long)]
+    all: bool,
+    /// Years to select (repeatable or comma separated)
+    #[arg(short, long, value_delimiter = ',')]
+    year: Vec<u16>,
+    /// Days to select (repeatable or comma separated)
+    #[arg(short, long, value_delimiter = ',', requires = "year")]
+    day: Vec<u8>,
+    /// Only select this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
 }
 
-#[allow(dead_code)]
-fn run_2023() -> Result<(), AoCError<String>> {
-    use year_2023::*;
-    let year = 2023;
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
-
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
-
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
-
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
-
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
-
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
-
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
-
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
-
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
-
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
-
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
-
-    Ok(())
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Run the solutions on this many threads (all cores if no number is given)
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0")]
+    jobs: Option<usize>,
+    /// Read the puzzle input from this file instead of the input cache (single day only)
+    #[arg(short, long, requires = "day", conflicts_with = "profile")]
+    input: Option<PathBuf>,
+    /// Use the inputs of this profile instead of the default account
+    #[arg(long)]
+    profile: Option<String>,
+    /// Print the time spent per part and a summary table sorted by cost
+    #[arg(short, long)]
+    timings: bool,
+    /// Solutions slower than this are flagged in the summary (e.g. 1s, 250ms, 500us)
+    #[arg(short, long, default_value = "1s", value_parser = parse_duration)]
+    budget: Duration,
+    /// Format of the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Write the results to this file instead of stdout (json and junit only)
+    #[arg(short, long, requires = "format")]
+    output: Option<PathBuf>,
 }
 
-fn run_2024() -> Result<(), AoCError<String>> {
-    use year_2024::*;
-    let year = 2024;
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-/*
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
-
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
-
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
-
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
-
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
-
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
-
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
-
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
-
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
-
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
-
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
-*/
-    Ok(())
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Run the solutions on this many threads (all cores if no number is given)
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0")]
+    jobs: Option<usize>,
+    /// Store the results of solutions without a known answer in the answers file
+    #[arg(short, long)]
+    record: bool,
 }
 
-/*fn run_20XX() -> Result<(), AoCError<String>> {   // TODO add year
-    use year_20XX::*;   // TODO add year
-    let year = 20XX;  // TODO add year
-
-    // day 01
-    let input = get_input(year, 1)?;
-    println!("y{}-d01_p1: {}", year, day_01::part_1(&input).unwrap());
-    println!("y{}-d01_p2: {}", year, day_01::part_2(&input).unwrap());
-
-    // day02
-    let input = get_input(year, 2)?;
-    println!("y{}-d02_p1: {}", year, day_02::part_1(&input).unwrap());
-    println!("y{}-d02_p2: {}", year, day_02::part_2(&input).unwrap());
-
-    // day03
-    let input = get_input(year, 3)?;
-    println!("y{}-d03_p1: {}", year, day_03::part_1(&input).unwrap());
-    println!("y{}-d03_p2: {}", year, day_03::part_2(&input).unwrap());
-
-    // day04
-    let input = get_input(year, 4)?;
-    println!("y{}-d04_p1: {}", year, day_04::part_1(&input).unwrap());
-    println!("y{}-d04_p2: {}", year, day_04::part_2(&input).unwrap());
-
-    // day05
-    let input = get_input(year, 5)?;
-    println!("y{}-d05_p1: {}", year, day_05::part_1(&input).unwrap());
-    println!("y{}-d05_p2: {}", year, day_05::part_2(&input).unwrap());
-
-    // day06
-    let input = get_input(year, 6)?;
-    println!("y{}-d06_p1: {}", year, day_06::part_1(&input).unwrap());
-    println!("y{}-d06_p2: {}", year, day_06::part_2(&input).unwrap());
-
-    // day07
-    let input = get_input(year, 7)?;
-    println!("y{}-d07_p1: {}", year, day_07::part_1(&input).unwrap());
-    println!("y{}-d07_p2: {}", year, day_07::part_2(&input).unwrap());
-
-    // day08
-    let input = get_input(year, 8)?;
-    println!("y{}-d08_p1: {}", year, day_08::part_1(&input).unwrap());
-    println!("y{}-d08_p2: {}", year, day_08::part_2(&input).unwrap());
-
-    // day09
-    let input = get_input(year, 9)?;
-    println!("y{}-d09_p1: {}", year, day_09::part_1(&input).unwrap());
-    println!("y{}-d09_p2: {}", year, day_09::part_2(&input).unwrap());
-
-    // day10
-    let input = get_input(year, 10)?;
-    println!("y{}-d10_p1: {}", year, day_10::part_1(&input).unwrap());
-    println!("y{}-d10_p2: {}", year, day_10::part_2(&input).unwrap());
-
-    // day11
-    let input = get_input(year, 11)?;
-    println!("y{}-d11_p1: {}", year, day_11::part_1(&input).unwrap());
-    println!("y{}-d11_p2: {}", year, day_11::part_2(&input).unwrap());
-
-    // day12
-    let input = get_input(year, 12)?;
-    println!("y{}-d12_p1: {}", year, day_12::part_1(&input).unwrap());
-    println!("y{}-d12_p2: {}", year, day_12::part_2(&input).unwrap());
-
-    // day13
-    let input = get_input(year, 13)?;
-    println!("y{}-d13_p1: {}", year, day_13::part_1(&input).unwrap());
-    println!("y{}-d13_p2: {}", year, day_13::part_2(&input).unwrap());
-
-    // day14
-    let input = get_input(year, 14)?;
-    println!("y{}-d14_p1: {}", year, day_14::part_1(&input).unwrap());
-    println!("y{}-d14_p2: {}", year, day_14::part_2(&input).unwrap());
-
-    // day15
-    let input = get_input(year, 15)?;
-    println!("y{}-d15_p1: {}", year, day_15::part_1(&input).unwrap());
-    println!("y{}-d15_p2: {}", year, day_15::part_2(&input).unwrap());
+#[derive(Args)]
+struct CompareArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// Run the solutions on this many threads (all cores if no number is given)
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0")]
+    jobs: Option<usize>,
+    /// Profiles to compare (comma separated), all configured profiles by default
+    #[arg(long, value_delimiter = ',')]
+    profiles: Vec<String>,
+}
 
-    // day16
-    let input = get_input(year, 16)?;
-    println!("y{}-d16_p1: {}", year, day_16::part_1(&input).unwrap());
-    println!("y{}-d16_p2: {}", year, day_16::part_2(&input).unwrap());
+#[derive(Args)]
+struct NewArgs {
+    #[arg(short, long)]
+    year: u16,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
 
-    // day17
-    let input = get_input(year, 17)?;
-    println!("y{}-d17_p1: {}", year, day_17::part_1(&input).unwrap());
-    println!("y{}-d17_p2: {}", year, day_17::part_2(&input).unwrap());
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(short, long)]
+    year: u16,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Submit this answer instead of running the solution
+    #[arg(short, long)]
+    answer: Option<String>,
+}
 
-    // day18
-    let input = get_input(year, 18)?;
-    println!("y{}-d18_p1: {}", year, day_18::part_1(&input).unwrap());
-    println!("y{}-d18_p2: {}", year, day_18::part_2(&input).unwrap());
+#[derive(Copy, Clone, Eq, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Junit,
+}
 
-    // day19
-    let input = get_input(year, 19)?;
-    println!("y{}-d19_p1: {}", year, day_19::part_1(&input).unwrap());
-    println!("y{}-d19_p2: {}", year, day_19::part_2(&input).unwrap());
+impl Selection {
+    fn select(&self) -> Result<Vec<Solution>, AoCError<String>> {
+        let solutions = solution::get_all().into_iter()
+            .filter(|solution| self.year.is_empty() || self.year.contains(&solution.year))
+            .filter(|solution| self.day.is_empty() || self.day.contains(&solution.day))
+            .filter(|solution| self.part.is_none_or(|part| part == solution.part))
+            .collect::<Vec<_>>();
+        if solutions.is_empty() {
+            return Err(AoCError::BadInputFormat(
+                "No solution is registered for the selection.".to_string()))
+        }
+        Ok(solutions)
+    }
+}
 
-    // day20
-    let input = get_input(year, 20)?;
-    println!("y{}-d20_p1: {}", year, day_20::part_1(&input).unwrap());
-    println!("y{}-d20_p2: {}", year, day_20::part_2(&input).unwrap());
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let res = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Compare(args) => compare(&args),
+        Command::New(args) => create_day(&args),
+        Command::Submit(args) => submit(&args),
+    };
+    match res {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}", e.get_report());
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    // day21
-    let input = get_input(year, 21)?;
-    println!("y{}-d21_p1: {}", year, day_21::part_1(&input).unwrap());
-    println!("y{}-d21_p2: {}", year, day_21::part_2(&input).unwrap());
+/// Runs the selected solutions. Returns whether all of them succeeded.
+fn run(args: &RunArgs) -> Result<bool, AoCError<String>> {
+    let solutions = args.selection.select()?;
+    if args.input.is_some() && (args.selection.year.len() != 1 || args.selection.day.len() != 1) {
+        return Err(AoCError::BadInputFormat(
+            "A custom input file requires exactly one year and one day.".to_string()))
+    }
+    if args.output.is_some() && args.format == Format::Text {
+        return Err(AoCError::BadInputFormat(
+            "An output file requires the json or junit format.".to_string()))
+    }
+    let source = match (&args.input, &args.profile) {
+        (Some(path), _) => InputSource::File(path),
+        (None, Some(profile)) => InputSource::Profile(profile),
+        (None, None) => InputSource::Cache,
+    };
+    let reports = run_solutions(&solutions, source, args.jobs, |report| {
+        if !report.outcome.is_solved() {
+            eprintln!("{}", report);
+        } else if args.format != Format::Text {
+            // stdout is reserved for the machine-readable results
+        } else if args.timings {
+            println!("{} ({})", report, format_duration(report.duration));
+        } else {
+            println!("{}", report);
+        }
+    });
+    if args.timings {
+        let summary = Summary::new(&reports, args.budget);
+        if args.format == Format::Text {
+            println!();
+            println!("{}", summary);
+        } else {
+            eprintln!("{}", summary);
+        }
+    }
+    let results = match args.format {
+        Format::Text => None,
+        Format::Json => Some(runner::to_json(&reports)),
+        Format::Junit => Some(runner::to_junit(&reports)),
+    };
+    if let Some(results) = results {
+        match &args.output {
+            Some(path) => fs::write(path, results)
+                .map_err(|e| AoCError::IOError(format!(
+                    "Writing results to '{}' failed: {}", path.display(), e)))?,
+            None => println!("{}", results),
+        }
+    }
+    let failed = reports.iter()
+        .filter(|report| !report.outcome.is_solved())
+        .count();
+    if failed > 0 {
+        eprintln!("{} of {} solutions failed", failed, reports.len());
+    }
+    Ok(failed == 0)
+}
 
-    // day22
-    let input = get_input(year, 22)?;
-    println!("y{}-d22_p1: {}", year, day_22::part_1(&input).unwrap());
-    println!("y{}-d22_p2: {}", year, day_22::part_2(&input).unwrap());
+fn run_solutions<F: FnMut(&Report)>(solutions: &[Solution], source: InputSource,
+                                    jobs: Option<usize>, on_report: F) -> Vec<Report> {
+    match jobs {
+        None | Some(1) => runner::run(solutions, source, on_report),
+        Some(0) => runner::run_parallel(solutions, source, num_cpus::get(), on_report),
+        Some(jobs) => runner::run_parallel(solutions, source, jobs, on_report),
+    }
+}
 
-    // day23
-    let input = get_input(year, 23)?;
-    println!("y{}-d23_p1: {}", year, day_23::part_1(&input).unwrap());
-    println!("y{}-d23_p2: {}", year, day_23::part_2(&input).unwrap());
+/// Runs the selected solutions and compares them to the known answers. Returns whether all of them
+/// passed.
+fn verify(args: &VerifyArgs) -> Result<bool, AoCError<String>> {
+    let solutions = args.selection.select()?;
+    let mut answers = HashMap::new();
+    for year in solutions.iter().map(|solution| solution.year).dedup() {
+        answers.insert(year, Answers::load(year)?);
+    }
+    let (mut passed, mut failed, mut missing, mut errors) = (0, 0, 0, 0);
+    run_solutions(&solutions, InputSource::Cache, args.jobs, |report| {
+        let year_answers = answers.get_mut(&report.solution.year)
+            .expect("Answers of all selected years are loaded");
+        let (day, part) = (report.solution.day, report.solution.part);
+        let answer = match &report.outcome {
+            Outcome::Solved(answer) => answer,
+            _ => {
+                errors += 1;
+                eprintln!("{}", report);
+                return
+            }
+        };
+        match year_answers.check(day, part, answer) {
+            Verdict::Pass => {
+                passed += 1;
+                println!("{}: pass", report.solution);
+            }
+            Verdict::Fail(expected) => {
+                failed += 1;
+                println!("{}: FAIL (expected {}, found {})", report.solution, expected, answer);
+            }
+            Verdict::Missing if args.record => {
+                missing += 1;
+                year_answers.set(day, part, answer.clone());
+                println!("{}: missing, recorded {}", report.solution, answer);
+            }
+            Verdict::Missing => {
+                missing += 1;
+                println!("{}: missing (found {})", report.solution, answer);
+            }
+        }
+    });
+    if args.record {
+        for year_answers in answers.values() {
+            year_answers.save()?;
+        }
+    }
+    println!("{} passed, {} failed, {} missing, {} errors", passed, failed, missing, errors);
+    Ok(failed == 0 && errors == 0)
+}
 
-    // day24
-    let input = get_input(year, 24)?;
-    println!("y{}-d24_p1: {}", year, day_24::part_1(&input).unwrap());
-    println!("y{}-d24_p2: {}", year, day_24::part_2(&input).unwrap());
+/// Runs the selected solutions for every profile and prints their answers side by side. Returns
+/// whether all of them succeeded.
+fn compare(args: &CompareArgs) -> Result<bool, AoCError<String>> {
+    let solutions = args.selection.select()?;
+    let profiles = if args.profiles.is_empty() {
+        InputConfig::get()?.get_profile_names()
+    } else {
+        args.profiles.clone()
+    };
+    if profiles.is_empty() {
+        return Err(AoCError::BadInputFormat(
+            "No profiles are configured, add them to aoc.toml or AOC_PROFILES.".to_string()))
+    }
+    let mut failed = 0;
+    let mut reports = Vec::with_capacity(profiles.len());
+    for profile in profiles.iter() {
+        reports.push(run_solutions(&solutions, InputSource::Profile(profile), args.jobs, |report| {
+            if !report.outcome.is_solved() {
+                failed += 1;
+                eprintln!("[{}] {}", profile, report);
+            }
+        }));
+    }
+    println!("{}", ProfileComparison::new(&profiles, &reports));
+    Ok(failed == 0)
+}
 
-    // day25
-    let input = get_input(year, 25)?;
-    println!("y{}-d25_p1: {}", year, day_25::part_1(&input).unwrap());
-    println!("y{}-d25_p2: {}", year, day_25::part_2(&input).unwrap());
+fn create_day(args: &NewArgs) -> Result<bool, AoCError<String>> {
+    let src_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    for path in scaffold::create_day(&src_dir, args.year, args.day)? {
+        println!("Updated {}", path.display());
+    }
+    Ok(true)
+}
 
-    Ok(())
-}*/
+/// Submits the answer of a solution and records the response. Returns whether it was correct.
+fn submit(args: &SubmitArgs) -> Result<bool, AoCError<String>> {
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let solution = solution::get(args.year, args.day, args.part)
+                .ok_or_else(|| AoCError::BadInputFormat(format!(
+                    "No solution is registered for {} day {} part {}.",
+                    args.year, args.day, args.part)))?;
+            let report = runner::run(&[solution], InputSource::Cache, |_| {}).remove(0);
+            match report.outcome {
+                Outcome::Solved(answer) => answer,
+                _ => return Err(AoCError::NoSolutionFoundError(report.to_string())),
+            }
+        }
+    };
+    let mut history = History::load(args.year)?;
+    let mut answers = Answers::load(args.year)?;
+    let submitter = HttpSubmitter::new(InputConfig::get()?.clone());
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)
+        .map_err(|e| AoCError::IOError(format!("Reading the system time failed: {}", e)))?
+        .as_secs();
+    println!("Submitting '{}' for {} day {} part {}", answer, args.year, args.day, args.part);
+    let response = history.submit(args.day, args.part, &answer, &mut answers, &submitter, now)?;
+    history.save()?;
+    println!("Response: {}", response.get_description());
+    if response == Response::Correct {
+        answers.save()?;
+    }
+    Ok(response == Response::Correct)
+}
 
-fn main() -> Result<(), AoCError<String>> {
-    // run_2015()?;
-    // run_2016()?;
-    // run_2017()?;
-    // run_2023()?;
-    run_2024()?;
+fn parse_duration(text: &str) -> Result<Duration, String> {
+    let split = text.find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let value = text[..split].parse::<f64>()
+        .map_err(|e| format!("Parsing duration '{}' failed: {}", text, e))?;
+    let seconds = match &text[split..] {
+        "s" | "" => value,
+        "ms" => value / 1e3,
+        "us" | "µs" => value / 1e6,
+        unit => return Err(format!("Unknown duration unit '{}', expected s, ms or us", unit)),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|e| format!("Duration '{}' is out of range: {}", text, e))
+}
 
-    Ok(())
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_parse_duration() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert!(parse_duration("99999999999999999999999s").is_err());
+        assert!(parse_duration("5min").is_err());
+    }
+
+    #[test]
+    fn check_budget_arg() {
+        for budget in ["99999999999999999999999s", "5min"] {
+            let error = Cli::try_parse_from(["aoc", "run", "--all", "--budget", budget])
+                .err().expect("The budget is rejected");
+            assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+        }
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--budget", "500us"]).is_ok());
+    }
 }
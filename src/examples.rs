@@ -0,0 +1,146 @@
+use std::path::Path;
+use crate::errors::{AoCError, AoCResult};
+use crate::input::{get_description, normalize_lines};
+
+/// Examples quoted in a puzzle description.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Examples {
+    /// Contents of all '<pre><code>' blocks, in order of appearance.
+    pub inputs: Vec<Vec<String>>,
+    /// Example answer of each part found in the description. Part 2 is only included if it was
+    /// unlocked when the description was cached.
+    pub answers: Vec<Option<String>>,
+}
+
+impl Examples {
+    /// Returns the content of the 'index'th example block.
+    pub fn get_input(&self, index: usize) -> AoCResult<&Vec<String>> {
+        self.inputs.get(index)
+            .ok_or_else(|| AoCError::UnexpectedInputLength(format!(
+                "The description only contains {} examples, example {} requested.",
+                self.inputs.len(), index)))
+    }
+
+    /// Returns the example answer of the given part, if one was found.
+    pub fn get_answer(&self, part: u8) -> Option<&str> {
+        self.answers.get((part as usize).checked_sub(1)?)
+            .and_then(|answer| answer.as_deref())
+    }
+}
+
+/// Returns the examples of the given day from the (cached) puzzle description.
+pub fn get_examples(year: u16, day: u8) -> AoCResult<Examples> {
+    Ok(parse_examples(&get_description(year, day)?))
+}
+
+/// Returns the examples of a puzzle description saved to the given file.
+pub fn get_examples_from_file(path: &Path) -> AoCResult<Examples> {
+    let html = std::fs::read_to_string(path)
+        .map_err(|e| AoCError::IOError(format!(
+            "Reading puzzle description '{}' failed: {}", path.display(), e)))?;
+    Ok(parse_examples(&html))
+}
+
+/// Extracts the '<pre><code>' blocks and the example answers of a puzzle description.
+/// Each part is an '<article>', its example answer is taken to be the last emphasized code
+/// ('<code><em>..</em></code>') outside of the example blocks. This matches the usual
+/// "[..] produces <code><em>142</em></code>." but is a heuristic.
+pub fn parse_examples(html: &str) -> Examples {
+    let articles = get_elements(html, "<article", "</article>");
+    let articles = if articles.is_empty() {
+        vec![html]
+    } else {
+        articles
+    };
+
+    let mut examples = Examples::default();
+    for article in articles {
+        let mut text = String::new();
+        let mut rest = article;
+        while let Some(start) = rest.find("<pre><code>") {
+            text.push_str(&rest[..start]);
+            let block = &rest[start+"<pre><code>".len()..];
+            let end = block.find("</code></pre>").unwrap_or(block.len());
+            examples.inputs.push(normalize_lines(&to_text(&block[..end])));
+            rest = block[end..].strip_prefix("</code></pre>").unwrap_or("");
+        }
+        text.push_str(rest);
+        let answer = ["<code><em>", "<em><code>"].iter()
+            .filter_map(|tag| text.rfind(tag).map(|index| index+tag.len()))
+            .max()
+            .map(|start| {
+                let end = text[start..].find("</").map_or(text.len(), |end| start+end);
+                to_text(&text[start..end])
+            });
+        examples.answers.push(answer);
+    }
+    examples
+}
+
+/// Returns the contents of all elements starting with 'open' (e.g. '<article') and ending with
+/// 'close'.
+fn get_elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut elements = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let element = &rest[start..];
+        let content_start = element.find('>').map_or(element.len(), |index| index+1);
+        let content_end = element.find(close).unwrap_or(element.len());
+        elements.push(&element[content_start.min(content_end)..content_end]);
+        rest = &element[content_end..];
+    }
+    elements
+}
+
+/// Removes all tags and resolves the entities used by the puzzle descriptions.
+fn to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DESCRIPTION: &str = "<article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>\n\
+        <p>For example:</p>\n<pre><code>1abc2\npqr3stu8vwx\n<em>a1b2c3d4e5f</em>\n</code></pre>\n\
+        <p>The values are <code>12</code> and <code>38</code>. Adding these together produces \
+        <code><em>142</em></code>.</p>\n</article>\n\
+        <p>Your puzzle answer was <code>54630</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\n\
+        <pre><code>two1nine\n&lt;x&gt; &amp; y\n</code></pre>\n\
+        <p>Adding these together produces <em><code>281</code></em>.</p>\n</article>\n";
+
+    #[test]
+    fn check_parse_examples() -> AoCResult<()> {
+        let examples = parse_examples(DESCRIPTION);
+        assert_eq!(examples.get_input(0)?, &vec!["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f"]);
+        assert_eq!(examples.get_input(1)?, &vec!["two1nine", "<x> & y"]);
+        assert!(examples.get_input(2).is_err());
+        assert_eq!(examples.get_answer(1), Some("142"));
+        assert_eq!(examples.get_answer(2), Some("281"));
+        assert_eq!(examples.get_answer(3), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_parse_examples_without_articles() {
+        let examples = parse_examples("<pre><code>5 10 25\n</code></pre><code><em>x</em></code>");
+        assert_eq!(examples.inputs, vec![vec!["5 10 25".to_string()]]);
+        assert_eq!(examples.answers, vec![Some("x".to_string())]);
+    }
+}
@@ -0,0 +1,477 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use num::{PrimInt, Signed};
+use crate::errors::{AoCError, AoCResult};
+use crate::geometrics::{Direction8, Point3D};
+
+/// Position on a hexagonal grid in axial coordinates. The third cube coordinate 's' is implied by
+/// 'q + r + s = 0'. 'r' grows southwards, see https://www.redblobgames.com/grids/hexagons/.
+///
+/// North is at the top, like for 'Direction8' on a 'Point2D' (negative 'y'). 'FlatDirection' and
+/// 'PointyDirection' name the neighbors like the 'Direction8' they point to and convert from and
+/// into it. Their 'get_right(..)' and 'get_left(..)' turn by 60 degrees.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Hex<I> {
+    pub q: I,
+    pub r: I,
+}
+
+impl<I> Hex<I> {
+    pub fn new(q: I, r: I) -> Self {
+        Self { q, r }
+    }
+}
+
+impl<I: PrimInt + Signed> Hex<I> {
+    pub fn get_s(&self) -> I {
+        -self.q - self.r
+    }
+
+    /// Returns the cube coordinates (q, r, s) as 'x', 'y' and 'z'.
+    pub fn to_cube(&self) -> Point3D<I> {
+        Point3D {
+            x: self.q,
+            y: self.r,
+            z: self.get_s(),
+        }
+    }
+
+    /// Creates the position from cube coordinates (q, r, s) given as 'x', 'y' and 'z'.
+    pub fn from_cube(cube: &Point3D<I>) -> AoCResult<Self> {
+        if !(cube.x + cube.y + cube.z).is_zero() {
+            return Err(AoCError::BadInputFormat(
+                "Cube coordinates need to sum up to zero.".to_string()))
+        }
+        Ok(Self::new(cube.x, cube.y))
+    }
+
+    /// Returns the number of steps needed to reach 'other'.
+    pub fn distance(&self, other: &Self) -> I {
+        let diff = *self - *other;
+        (diff.q.abs() + diff.r.abs() + diff.get_s().abs()) / (I::one() + I::one())
+    }
+
+    /// Returns the number of steps needed to reach the origin.
+    pub fn get_length(&self) -> I {
+        self.distance(&Self::new(I::zero(), I::zero()))
+    }
+
+    /// Returns the six adjacent positions in counterclockwise order.
+    pub fn get_neighbors(&self) -> Vec<Self> {
+        (0..6).map(|index| *self + get_unit(index)).collect()
+    }
+
+    /// Returns the position rotated by 60 degrees clockwise around the origin.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.r, -self.get_s())
+    }
+
+    /// Returns the position rotated by 60 degrees counterclockwise around the origin.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(-self.get_s(), -self.q)
+    }
+
+    /// Returns the position rotated by 60 degrees clockwise around 'center' 'steps' times.
+    /// Negative 'steps' rotate counterclockwise.
+    pub fn rotate_around(&self, center: &Self, steps: i32) -> Self {
+        let mut relative = *self - *center;
+        for _ in 0..steps.rem_euclid(6) {
+            relative = relative.rotate_right();
+        }
+        relative + *center
+    }
+
+    /// Returns all positions with a distance of exactly 'radius'. Starts south west of the
+    /// position and continues counterclockwise.
+    pub fn get_ring(&self, radius: usize) -> Vec<Self> {
+        if radius == 0 {
+            return vec![*self]
+        }
+        let mut ring = Vec::with_capacity(6*radius);
+        let mut current = *self + get_unit::<I>(4) * I::from(radius)
+            .expect("Radius fits into the coordinate type");
+        for index in 0..6 {
+            for _ in 0..radius {
+                ring.push(current);
+                current = current + get_unit(index);
+            }
+        }
+        ring
+    }
+
+    /// Returns all positions with a distance of at most 'radius', ordered by distance. Each ring
+    /// is ordered like 'get_ring(..)'.
+    pub fn get_spiral(&self, radius: usize) -> Vec<Self> {
+        (0..=radius).flat_map(|radius| self.get_ring(radius)).collect()
+    }
+}
+
+/// Returns one of the six axial unit vectors. The index counts counterclockwise, starting with
+/// south east for flat tops and east for pointy tops.
+fn get_unit<I: Signed>(index: usize) -> Hex<I> {
+    let (q, r) = match index % 6 {
+        0 => (I::one(), I::zero()),
+        1 => (I::one(), -I::one()),
+        2 => (I::zero(), -I::one()),
+        3 => (-I::one(), I::zero()),
+        4 => (-I::one(), I::one()),
+        _ => (I::zero(), I::one()),
+    };
+    Hex::new(q, r)
+}
+
+impl<I: Display> Display for Hex<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl<I: Add<Output = I>> Add for Hex<I> {
+    type Output = Hex<I>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+impl<I: AddAssign> AddAssign for Hex<I> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+impl<I: Sub<Output = I>> Sub for Hex<I> {
+    type Output = Hex<I>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+impl<I: SubAssign> SubAssign for Hex<I> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+impl<I: Neg<Output = I>> Neg for Hex<I> {
+    type Output = Hex<I>;
+    fn neg(self) -> Self::Output {
+        Self {
+            q: -self.q,
+            r: -self.r,
+        }
+    }
+}
+impl<I: Copy + Mul<Output = I>> Mul<I> for Hex<I> {
+    type Output = Hex<I>;
+    fn mul(self, rhs: I) -> Self::Output {
+        Self {
+            q: self.q * rhs,
+            r: self.r * rhs,
+        }
+    }
+}
+
+/// Directions of hexagons with flat tops, neighbors share the northern and southern edges. There is
+/// no neighbor to the east or west.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl FlatDirection {
+    /// Parses the abbreviations 'n', 'ne', 'se', 's', 'sw' and 'nw' (upper- and lowercase).
+    pub fn parse(str: &str) -> AoCResult<Self> {
+        match str.to_ascii_lowercase().as_str() {
+            "n" => Ok(Self::North),
+            "ne" => Ok(Self::NorthEast),
+            "se" => Ok(Self::SouthEast),
+            "s" => Ok(Self::South),
+            "sw" => Ok(Self::SouthWest),
+            "nw" => Ok(Self::NorthWest),
+            _ => Err(AoCError::BadInputFormat(format!("Parsing FlatDirection failed. Only 'n', \
+                'ne', 'se', 's', 'sw' and 'nw' supported. Found '{}'", str))),
+        }
+    }
+
+    pub fn get_offset<I: Signed>(&self) -> Hex<I> {
+        get_unit(self.get_index())
+    }
+
+    pub fn get_right(&self) -> Self {
+        Self::from_index(self.get_index() + 5)
+    }
+
+    pub fn get_left(&self) -> Self {
+        Self::from_index(self.get_index() + 1)
+    }
+
+    pub fn get_opposing(&self) -> Self {
+        Self::from_index(self.get_index() + 3)
+    }
+
+    pub fn get_all_directions() -> Vec<Self> {
+        vec![
+            Self::North,
+            Self::NorthEast,
+            Self::SouthEast,
+            Self::South,
+            Self::SouthWest,
+            Self::NorthWest,
+        ]
+    }
+
+    fn get_index(&self) -> usize {
+        match self {
+            FlatDirection::SouthEast => 0,
+            FlatDirection::NorthEast => 1,
+            FlatDirection::North => 2,
+            FlatDirection::NorthWest => 3,
+            FlatDirection::SouthWest => 4,
+            FlatDirection::South => 5,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index % 6 {
+            0 => FlatDirection::SouthEast,
+            1 => FlatDirection::NorthEast,
+            2 => FlatDirection::North,
+            3 => FlatDirection::NorthWest,
+            4 => FlatDirection::SouthWest,
+            _ => FlatDirection::South,
+        }
+    }
+}
+
+impl From<FlatDirection> for Direction8 {
+    fn from(dir: FlatDirection) -> Self {
+        match dir {
+            FlatDirection::North => Self::North,
+            FlatDirection::NorthEast => Self::NorthEast,
+            FlatDirection::SouthEast => Self::SouthEast,
+            FlatDirection::South => Self::South,
+            FlatDirection::SouthWest => Self::SouthWest,
+            FlatDirection::NorthWest => Self::NorthWest,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for FlatDirection {
+    type Error = AoCError<String>;
+
+    fn try_from(dir: Direction8) -> AoCResult<Self> {
+        match dir {
+            Direction8::North => Ok(Self::North),
+            Direction8::NorthEast => Ok(Self::NorthEast),
+            Direction8::SouthEast => Ok(Self::SouthEast),
+            Direction8::South => Ok(Self::South),
+            Direction8::SouthWest => Ok(Self::SouthWest),
+            Direction8::NorthWest => Ok(Self::NorthWest),
+            dir => Err(AoCError::BadInputFormat(format!(
+                "Hexagons with flat tops have no neighbor to the {}.", dir))),
+        }
+    }
+}
+
+/// Directions of hexagons with pointy tops, neighbors share the eastern and western edges. There is
+/// no neighbor to the north or south.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PointyDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl PointyDirection {
+    /// Parses the abbreviations 'e', 'se', 'sw', 'w', 'nw' and 'ne' (upper- and lowercase).
+    pub fn parse(str: &str) -> AoCResult<Self> {
+        match str.to_ascii_lowercase().as_str() {
+            "e" => Ok(Self::East),
+            "se" => Ok(Self::SouthEast),
+            "sw" => Ok(Self::SouthWest),
+            "w" => Ok(Self::West),
+            "nw" => Ok(Self::NorthWest),
+            "ne" => Ok(Self::NorthEast),
+            _ => Err(AoCError::BadInputFormat(format!("Parsing PointyDirection failed. Only \
+                'e', 'se', 'sw', 'w', 'nw' and 'ne' supported. Found '{}'", str))),
+        }
+    }
+
+    /// Parses a list of directions without separators, e.g. 'esenee'.
+    pub fn parse_concatenated(str: &str) -> AoCResult<Vec<Self>> {
+        let mut directions = vec![];
+        let mut rest = str;
+        while !rest.is_empty() {
+            let len = if rest.starts_with(['n', 's', 'N', 'S']) { 2 } else { 1 };
+            let token = rest.get(..len).unwrap_or(rest);
+            directions.push(Self::parse(token)?);
+            rest = &rest[token.len()..];
+        }
+        Ok(directions)
+    }
+
+    pub fn get_offset<I: Signed>(&self) -> Hex<I> {
+        get_unit(self.get_index())
+    }
+
+    pub fn get_right(&self) -> Self {
+        Self::from_index(self.get_index() + 5)
+    }
+
+    pub fn get_left(&self) -> Self {
+        Self::from_index(self.get_index() + 1)
+    }
+
+    pub fn get_opposing(&self) -> Self {
+        Self::from_index(self.get_index() + 3)
+    }
+
+    pub fn get_all_directions() -> Vec<Self> {
+        vec![
+            Self::East,
+            Self::SouthEast,
+            Self::SouthWest,
+            Self::West,
+            Self::NorthWest,
+            Self::NorthEast,
+        ]
+    }
+
+    fn get_index(&self) -> usize {
+        match self {
+            PointyDirection::East => 0,
+            PointyDirection::NorthEast => 1,
+            PointyDirection::NorthWest => 2,
+            PointyDirection::West => 3,
+            PointyDirection::SouthWest => 4,
+            PointyDirection::SouthEast => 5,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index % 6 {
+            0 => PointyDirection::East,
+            1 => PointyDirection::NorthEast,
+            2 => PointyDirection::NorthWest,
+            3 => PointyDirection::West,
+            4 => PointyDirection::SouthWest,
+            _ => PointyDirection::SouthEast,
+        }
+    }
+}
+
+impl From<PointyDirection> for Direction8 {
+    fn from(dir: PointyDirection) -> Self {
+        match dir {
+            PointyDirection::East => Self::East,
+            PointyDirection::SouthEast => Self::SouthEast,
+            PointyDirection::SouthWest => Self::SouthWest,
+            PointyDirection::West => Self::West,
+            PointyDirection::NorthWest => Self::NorthWest,
+            PointyDirection::NorthEast => Self::NorthEast,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for PointyDirection {
+    type Error = AoCError<String>;
+
+    fn try_from(dir: Direction8) -> AoCResult<Self> {
+        match dir {
+            Direction8::East => Ok(Self::East),
+            Direction8::SouthEast => Ok(Self::SouthEast),
+            Direction8::SouthWest => Ok(Self::SouthWest),
+            Direction8::West => Ok(Self::West),
+            Direction8::NorthWest => Ok(Self::NorthWest),
+            Direction8::NorthEast => Ok(Self::NorthEast),
+            dir => Err(AoCError::BadInputFormat(format!(
+                "Hexagons with pointy tops have no neighbor to the {}.", dir))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_distance() -> AoCResult<()> {
+        let moves = ["ne", "ne", "s", "s"].iter()
+            .map(|str| FlatDirection::parse(str))
+            .collect::<AoCResult<Vec<_>>>()?;
+        let position = moves.iter()
+            .fold(Hex::default(), |position, dir| position + dir.get_offset::<i32>());
+        assert_eq!(position.get_length(), 2);
+        assert_eq!(Hex::new(3, -1).distance(&Hex::new(-2, 2)), 5);
+        assert_eq!(Hex::from_cube(&Hex::new(2, -5).to_cube())?, Hex::new(2, -5));
+        assert!(Hex::from_cube(&Point3D { x: 1, y: 1, z: 1 }).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_directions() -> AoCResult<()> {
+        assert_eq!(PointyDirection::parse_concatenated("nwwswee")?, vec![PointyDirection::NorthWest,
+            PointyDirection::West, PointyDirection::SouthWest, PointyDirection::East,
+            PointyDirection::East]);
+        assert!(PointyDirection::parse_concatenated("nx").is_err());
+        assert!(FlatDirection::parse("e").is_err());
+        assert_eq!(FlatDirection::North.get_right(), FlatDirection::NorthEast);
+        assert_eq!(PointyDirection::East.get_left(), PointyDirection::NorthEast);
+        for dir in FlatDirection::get_all_directions() {
+            assert_eq!(dir.get_opposing().get_offset::<i32>(), -dir.get_offset());
+            assert_eq!(dir.get_offset::<i32>().rotate_right(), dir.get_right().get_offset());
+        }
+        for dir in PointyDirection::get_all_directions() {
+            assert_eq!(dir.get_offset::<i32>().rotate_left(), dir.get_left().get_offset());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn check_direction8_conversions() -> AoCResult<()> {
+        // the signs of the pixel coordinates of the offsets, north and west are negative
+        for dir in FlatDirection::get_all_directions() {
+            let offset = dir.get_offset::<i32>();
+            let pixel = (offset.q.signum(), (2*offset.r + offset.q).signum());
+            let dir8 = Direction8::from(dir);
+            assert_eq!(pixel, dir8.get_offset::<i32>().into());
+            assert_eq!(FlatDirection::try_from(dir8)?, dir);
+        }
+        for dir in PointyDirection::get_all_directions() {
+            let offset = dir.get_offset::<i32>();
+            let pixel = ((2*offset.q + offset.r).signum(), offset.r.signum());
+            let dir8 = Direction8::from(dir);
+            assert_eq!(pixel, dir8.get_offset::<i32>().into());
+            assert_eq!(PointyDirection::try_from(dir8)?, dir);
+        }
+        assert!(FlatDirection::try_from(Direction8::East).is_err());
+        assert!(PointyDirection::try_from(Direction8::North).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_rings() {
+        let center = Hex::new(1, -1);
+        assert_eq!(center.get_neighbors().len(), 6);
+        assert!(center.get_neighbors().iter().all(|hex| hex.distance(&center) == 1));
+        let ring = center.get_ring(3);
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|hex| hex.distance(&center) == 3));
+        assert_eq!(center.get_spiral(2).len(), 19);
+        assert_eq!(center.get_spiral(2)[0], center);
+        assert_eq!(Hex::new(3, 0).rotate_around(&Hex::new(1, 0), 2), Hex::new(-1, 2));
+        assert_eq!(Hex::new(3, 0).rotate_around(&Hex::new(1, 0), -4), Hex::new(-1, 2));
+    }
+}
@@ -0,0 +1,793 @@
+use std::{env, fs};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use aoc_client::AocClient;
+use serde::Deserialize;
+use crate::errors::AoCError;
+use crate::md5_collision;
+
+/// Environment variable naming the config file.
+pub const CONFIG_ENV_VAR: &str = "AOC_CONFIG";
+/// Environment variable overriding the root directory of the input cache.
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+/// Environment variable overriding the file naming scheme of the input cache.
+pub const FILE_PATTERN_ENV_VAR: &str = "AOC_INPUT_FILE_PATTERN";
+/// Environment variable overriding the session cookie file.
+pub const COOKIE_FILE_ENV_VAR: &str = "AOC_SESSION_COOKIE_FILE";
+/// Default environment variable containing the session cookie itself.
+pub const COOKIE_ENV_VAR: &str = "AOC_SESSION_COOKIE";
+/// Environment variable overriding the download backend ('offline', 'aoc' or 'local:<dir>').
+pub const BACKEND_ENV_VAR: &str = "AOC_INPUT_BACKEND";
+/// Environment variable adding profiles (comma separated) to the configured ones.
+pub const PROFILES_ENV_VAR: &str = "AOC_PROFILES";
+/// Environment variable overriding the server answers are submitted to.
+pub const SERVER_URL_ENV_VAR: &str = "AOC_SERVER_URL";
+
+const CONFIG_FILE: &str = "aoc.toml";
+/// Extension appended to the path of a cached input to get the path of its checksum.
+const CHECKSUM_EXTENSION: &str = "md5";
+/// Bodies adventofcode.com answers with instead of the input, e.g. if the session cookie expired.
+const ERROR_BODIES: [&str; 3] = [
+    "Please log in",
+    "Please don't repeatedly request this endpoint before it unlocks",
+    "404 Not Found",
+];
+
+/// Where inputs are cached and how the session cookie for downloading them is found.
+///
+/// The defaults can be overridden by a config file and by environment variables (in that order).
+/// The config file is taken from `AOC_CONFIG`, otherwise 'aoc.toml' in the current directory or
+/// in the crate root is used if it exists:
+/// ```toml
+/// [input]
+/// cache_dir = "/shared/aoc/input"
+/// file_pattern = "{year}/day_{day:02}.txt"
+/// session_cookie_file = "/shared/aoc/session_cookie"
+/// session_cookie_env = "AOC_SESSION"
+/// backend = "offline"
+/// server_url = "http://localhost:8080"
+///
+/// [input.profiles.alice]
+/// session_cookie_file = "/shared/aoc/alice_cookie"
+/// ```
+/// Relative paths in the config file are relative to the config file, the default paths are
+/// relative to the crate root.
+///
+/// Profiles are additional accounts with their own inputs, see 'get_profile(..)'.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputConfig {
+    /// Root directory of the input cache.
+    pub cache_dir: PathBuf,
+    /// Path of the cached input relative to 'cache_dir'. '{year}', '{day}' and '{day:02}' are
+    /// replaced accordingly.
+    pub file_pattern: String,
+    /// File containing the session cookie.
+    pub session_cookie_file: PathBuf,
+    /// Environment variable containing the session cookie. Takes precedence over the file if set.
+    pub session_cookie_env: String,
+    /// Where inputs missing in the cache are taken from.
+    pub backend: Backend,
+    /// Base URL of the server answers are submitted to, e.g. a local stand-in server.
+    pub server_url: String,
+    /// Settings of the known profiles, missing settings use the defaults of 'get_profile(..)'.
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Settings of a profile differing from the defaults.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub cache_dir: Option<PathBuf>,
+    pub session_cookie_file: Option<PathBuf>,
+    pub session_cookie_env: Option<String>,
+}
+
+/// Where inputs missing in the cache are taken from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Backend {
+    /// Missing inputs are an error, nothing is downloaded.
+    Offline,
+    /// Missing inputs are downloaded from adventofcode.com.
+    AocClient,
+    /// Missing inputs are copied from another input directory using the same file naming scheme.
+    LocalDirectory(PathBuf),
+}
+
+impl Backend {
+    /// Parses 'offline', 'aoc' or 'local:<dir>'. Relative directories are resolved against
+    /// 'base_dir'.
+    pub fn parse(str: &str, base_dir: &Path) -> Result<Self, AoCError<String>> {
+        match str {
+            "offline" => Ok(Self::Offline),
+            "aoc" => Ok(Self::AocClient),
+            str => match str.strip_prefix("local:") {
+                Some(dir) => Ok(Self::LocalDirectory(base_dir.join(dir))),
+                None => Err(AoCError::BadInputFormat(format!(
+                    "Unknown input backend '{}', expected 'offline', 'aoc' or 'local:<dir>'.",
+                    str))),
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    input: InputSection,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct InputSection {
+    cache_dir: Option<PathBuf>,
+    file_pattern: Option<String>,
+    session_cookie_file: Option<PathBuf>,
+    session_cookie_env: Option<String>,
+    backend: Option<String>,
+    server_url: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        Self {
+            cache_dir: root.join("input"),
+            file_pattern: "year_{year}/input_day_{day:02}.txt".to_string(),
+            session_cookie_file: root.join("input/session_cookie"),
+            session_cookie_env: COOKIE_ENV_VAR.to_string(),
+            backend: Backend::AocClient,
+            server_url: "https://adventofcode.com".to_string(),
+            profiles: BTreeMap::new(),
+        }
+    }
+}
+
+impl InputConfig {
+    /// Returns the configuration from the config file and the environment. It is only loaded once.
+    pub fn get() -> Result<&'static InputConfig, AoCError<String>> {
+        static CONFIG: OnceLock<Result<InputConfig, AoCError<String>>> = OnceLock::new();
+        CONFIG.get_or_init(Self::load).as_ref().map_err(|e| e.clone())
+    }
+
+    /// Loads the configuration from the config file and the environment.
+    pub fn load() -> Result<Self, AoCError<String>> {
+        let config_file = match env::var_os(CONFIG_ENV_VAR) {
+            Some(path) => Some(PathBuf::from(path)),
+            None => {
+                let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+                [PathBuf::from(CONFIG_FILE), crate_root.join(CONFIG_FILE)].into_iter()
+                    .find(|path| path.exists())
+            }
+        };
+        let mut config = Self::default();
+        if let Some(path) = config_file {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| AoCError::IOError(format!(
+                    "Reading config file '{}' failed: {}", path.display(), e)))?;
+            config = config.with_config_file(&content, path.parent().unwrap_or(Path::new("")))?;
+        }
+        config.with_env(|name| env::var(name).ok())
+    }
+
+    /// Applies the settings of a config file. Relative paths are resolved against 'base_dir'.
+    pub fn with_config_file(mut self, content: &str, base_dir: &Path)
+        -> Result<Self, AoCError<String>>
+    {
+        let file = toml::from_str::<ConfigFile>(content)
+            .map_err(|e| AoCError::BadInputFormat(format!("Parsing config file failed: {}", e)))?;
+        if let Some(cache_dir) = file.input.cache_dir {
+            self.cache_dir = base_dir.join(cache_dir);
+        }
+        if let Some(file_pattern) = file.input.file_pattern {
+            self.file_pattern = file_pattern;
+        }
+        if let Some(session_cookie_file) = file.input.session_cookie_file {
+            self.session_cookie_file = base_dir.join(session_cookie_file);
+        }
+        if let Some(session_cookie_env) = file.input.session_cookie_env {
+            self.session_cookie_env = session_cookie_env;
+        }
+        if let Some(backend) = file.input.backend {
+            self.backend = Backend::parse(&backend, base_dir)?;
+        }
+        if let Some(server_url) = file.input.server_url {
+            self.server_url = server_url;
+        }
+        for (name, profile) in file.input.profiles {
+            check_profile_name(&name)?;
+            let profile = Profile {
+                cache_dir: profile.cache_dir.map(|dir| base_dir.join(dir)),
+                session_cookie_file: profile.session_cookie_file.map(|file| base_dir.join(file)),
+                session_cookie_env: profile.session_cookie_env,
+            };
+            self.profiles.insert(name, profile);
+        }
+        Ok(self)
+    }
+
+    /// Applies the environment variable overrides. 'var' returns the value of a variable if set.
+    pub fn with_env<F: Fn(&str) -> Option<String>>(mut self, var: F)
+        -> Result<Self, AoCError<String>>
+    {
+        if let Some(cache_dir) = var(INPUT_DIR_ENV_VAR) {
+            self.cache_dir = PathBuf::from(cache_dir);
+        }
+        if let Some(file_pattern) = var(FILE_PATTERN_ENV_VAR) {
+            self.file_pattern = file_pattern;
+        }
+        if let Some(session_cookie_file) = var(COOKIE_FILE_ENV_VAR) {
+            self.session_cookie_file = PathBuf::from(session_cookie_file);
+        }
+        if let Some(backend) = var(BACKEND_ENV_VAR) {
+            self.backend = Backend::parse(&backend, Path::new(""))?;
+        }
+        if let Some(server_url) = var(SERVER_URL_ENV_VAR) {
+            self.server_url = server_url;
+        }
+        if let Some(profiles) = var(PROFILES_ENV_VAR) {
+            for name in profiles.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                check_profile_name(name)?;
+                self.profiles.entry(name.to_string()).or_default();
+            }
+        }
+        Ok(self)
+    }
+
+    /// Returns the names of all configured profiles.
+    pub fn get_profile_names(&self) -> Vec<String> {
+        self.profiles.keys().cloned().collect()
+    }
+
+    /// Returns the configuration of the given profile. Unless configured otherwise, a profile
+    /// caches its inputs in '<cache_dir>/<profile>' and reads its session cookie from the file
+    /// 'session_cookie' there or from '<session_cookie_env>_<PROFILE>'. A local directory backend
+    /// copies from '<dir>/<profile>'.
+    pub fn get_profile(&self, name: &str) -> Result<InputConfig, AoCError<String>> {
+        check_profile_name(name)?;
+        let profile = self.profiles.get(name).cloned().unwrap_or_default();
+        let cache_dir = profile.cache_dir.unwrap_or_else(|| self.cache_dir.join(name));
+        let session_cookie_file = profile.session_cookie_file
+            .unwrap_or_else(|| cache_dir.join("session_cookie"));
+        let session_cookie_env = profile.session_cookie_env
+            .unwrap_or_else(|| format!("{}_{}", self.session_cookie_env,
+                                       name.to_ascii_uppercase().replace('-', "_")));
+        let backend = match &self.backend {
+            Backend::LocalDirectory(dir) => Backend::LocalDirectory(dir.join(name)),
+            backend => backend.clone(),
+        };
+        Ok(InputConfig {
+            cache_dir,
+            file_pattern: self.file_pattern.clone(),
+            session_cookie_file,
+            session_cookie_env,
+            backend,
+            server_url: self.server_url.clone(),
+            profiles: BTreeMap::new(),
+        })
+    }
+
+    pub fn get_downloader(&self) -> Box<dyn Downloader> {
+        match &self.backend {
+            Backend::Offline => Box::new(OfflineDownloader),
+            Backend::AocClient => Box::new(AocClientDownloader::new(self.clone())),
+            Backend::LocalDirectory(dir) =>
+                Box::new(LocalDirectoryDownloader::new(dir.clone(), self.file_pattern.clone())),
+        }
+    }
+
+    /// Returns the path of the cached input of the given day.
+    pub fn get_path(&self, year: u16, day: u8) -> Result<PathBuf, AoCError<String>> {
+        if year < 2015 {
+            return Err(AoCError::IOError("AoC only started in 2015.".to_string()))
+        }
+        if day == 0 || day > 25 {
+            return Err(AoCError::IOError("Only days 1-25 supported.".to_string()))
+        }
+        let file_name = self.file_pattern
+            .replace("{year}", &year.to_string())
+            .replace("{day:02}", &format!("{:02}", day))
+            .replace("{day}", &day.to_string());
+        Ok(self.cache_dir.join(file_name))
+    }
+
+    /// Returns the path of the cached puzzle description of the given day, next to its input.
+    pub fn get_description_path(&self, year: u16, day: u8) -> Result<PathBuf, AoCError<String>> {
+        Ok(self.get_path(year, day)?.with_extension("html"))
+    }
+
+    /// Returns the session cookie, preferring the environment variable over the file.
+    pub fn get_session_cookie(&self) -> Result<String, AoCError<String>> {
+        if let Ok(cookie) = env::var(&self.session_cookie_env) {
+            if !cookie.trim().is_empty() {
+                return Ok(cookie.trim().to_string())
+            }
+        }
+        fs::read_to_string(&self.session_cookie_file)
+            .map(|cookie| cookie.trim().to_string())
+            .map_err(|e| AoCError::IOError(format!(
+                "Reading session cookie from '{}' failed (and '{}' is not set): {}",
+                self.session_cookie_file.display(), self.session_cookie_env, e)))
+    }
+}
+
+pub fn get_input(year: u16, day: u8) -> Result<Vec<String>, AoCError<String>> {
+    get_input_for_profile(year, day, None)
+}
+
+/// Returns the input of the given profile, or of the default account if 'profile' is None.
+pub fn get_input_for_profile(year: u16, day: u8, profile: Option<&str>)
+    -> Result<Vec<String>, AoCError<String>>
+{
+    let config = InputConfig::get()?;
+    match profile {
+        Some(name) => get_input_with_config(year, day, &config.get_profile(name)?),
+        None => get_input_with_config(year, day, config),
+    }
+}
+
+pub fn get_input_with_config(year: u16, day: u8, config: &InputConfig)
+    -> Result<Vec<String>, AoCError<String>>
+{
+    get_input_with_downloader(year, day, config, config.get_downloader().as_ref())
+}
+
+/// Reads the input from the cache, missing inputs are fetched using the given downloader and
+/// stored in the cache together with their checksum.
+pub fn get_input_with_downloader(year: u16, day: u8, config: &InputConfig,
+                                 downloader: &dyn Downloader)
+    -> Result<Vec<String>, AoCError<String>>
+{
+    let path = config.get_path(year, day)?;
+    if !path.exists() {
+        let content = downloader.download(year, day)?;
+        check_content(&content, &format!("Downloaded input of {} day {}", year, day))?;
+        write_content_to_file(&get_checksum_path(&path), get_checksum(&content))?;
+        write_content_to_file(&path, content)?;
+    }
+    let content = read_from_file(&path)?;
+    verify_checksum(&path, &content)?;
+    parse_input(&content, &format!("Cached input '{}'", path.display()))
+}
+
+/// Returns the HTML of the puzzle description, see 'get_description_with_downloader(..)'.
+pub fn get_description(year: u16, day: u8) -> Result<String, AoCError<String>> {
+    let config = InputConfig::get()?;
+    get_description_with_downloader(year, day, config, config.get_downloader().as_ref())
+}
+
+/// Reads the puzzle description from the cache, missing descriptions are fetched using the given
+/// downloader and stored in the cache. A page saved from the browser can be placed there instead.
+/// The second part is only included if it was unlocked when the description was cached, delete
+/// the cached file to fetch it again.
+pub fn get_description_with_downloader(year: u16, day: u8, config: &InputConfig,
+                                       downloader: &dyn Downloader)
+    -> Result<String, AoCError<String>>
+{
+    let path = config.get_description_path(year, day)?;
+    if path.exists() {
+        return read_from_file(&path)
+    }
+    let description = downloader.download_description(year, day)?;
+    write_content_to_file(&path, description.clone())?;
+    Ok(description)
+}
+
+/// Reads the input from the given file instead of the input cache. Nothing is downloaded.
+pub fn get_input_from_file(path: &Path) -> Result<Vec<String>, AoCError<String>> {
+    let content = read_from_file(path)?;
+    parse_input(&content, &format!("Input '{}'", path.display()))
+}
+
+/// Splits the content into lines after rejecting error pages of failed downloads. 'origin' names
+/// the content in error messages.
+pub fn parse_input(content: &str, origin: &str) -> Result<Vec<String>, AoCError<String>> {
+    check_content(content, origin)?;
+    Ok(normalize_lines(content))
+}
+
+/// Splits the content into lines. CRLF line endings are accepted and trailing empty lines are
+/// removed. Lines are not trimmed and lines of spaces are kept, they may be part of a grid.
+pub fn normalize_lines(content: &str) -> Vec<String> {
+    let mut lines = content.lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect::<Vec<_>>();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Rejects HTML pages and error messages saved instead of a puzzle input.
+fn check_content(content: &str, origin: &str) -> Result<(), AoCError<String>> {
+    let start = content.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        return Err(AoCError::IOError(format!(
+            "{} is an HTML page, not a puzzle input. Was the download redirected?", origin)))
+    }
+    if let Some(body) = ERROR_BODIES.iter().find(|body| content.contains(*body)) {
+        return Err(AoCError::IOError(format!(
+            "{} is an error message ('{}'), not a puzzle input. Is the session cookie valid?",
+            origin, body)))
+    }
+    if content.trim().is_empty() {
+        return Err(AoCError::IOError(format!("{} is empty.", origin)))
+    }
+    Ok(())
+}
+
+/// Profile names are used as directory names and in environment variables.
+fn check_profile_name(name: &str) -> Result<(), AoCError<String>> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(AoCError::BadInputFormat(format!(
+            "Invalid profile name '{}', only letters, digits, '-' and '_' are allowed.", name)))
+    }
+    Ok(())
+}
+
+fn get_checksum_path(path: &Path) -> PathBuf {
+    let mut checksum_path = path.as_os_str().to_owned();
+    checksum_path.push(format!(".{}", CHECKSUM_EXTENSION));
+    PathBuf::from(checksum_path)
+}
+
+fn get_checksum(content: &str) -> String {
+    md5_collision::hash(content).iter().fold(String::new(), |mut output, byte| {
+        let _ = write!(output, "{:02x}", byte);
+        output
+    })
+}
+
+/// Compares the cached input to its stored checksum. Inputs cached without a checksum get one.
+fn verify_checksum(path: &Path, content: &str) -> Result<(), AoCError<String>> {
+    let checksum_path = get_checksum_path(path);
+    let checksum = get_checksum(content);
+    match fs::read_to_string(&checksum_path) {
+        Ok(expected) if expected.trim() == checksum => Ok(()),
+        Ok(_) => Err(AoCError::IOError(format!(
+            "Cached input '{}' does not match its checksum '{}', it was corrupted or edited. \
+            Delete both files to download it again or only the checksum to keep the changes.",
+            path.display(), checksum_path.display()))),
+        Err(_) if !checksum_path.exists() => write_content_to_file(&checksum_path, checksum),
+        Err(e) => Err(AoCError::IOError(format!(
+            "Reading checksum '{}' failed: {}", checksum_path.display(), e))),
+    }
+}
+
+fn read_from_file(path: &Path) -> Result<String, AoCError<String>> {
+    fs::read_to_string(path)
+        .map_err(|e| AoCError::IOError(format!(
+            "Reading from file '{}' failed: {}", path.display(), e)))
+}
+
+pub trait Downloader {
+    /// Returns the puzzle input of the given day.
+    fn download(&self, year: u16, day: u8) -> Result<String, AoCError<String>>;
+
+    /// Returns the HTML of the puzzle description of the given day.
+    fn download_description(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        Err(AoCError::IOError(format!(
+            "Puzzle description of {} day {} is not cached and the downloader does not support \
+            descriptions.", year, day)))
+    }
+}
+
+/// Never downloads anything, for environments where network access is a bug.
+pub struct OfflineDownloader;
+
+impl Downloader for OfflineDownloader {
+    fn download(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        Err(AoCError::IOError(format!(
+            "Input of {} day {} is not cached and downloading is disabled (offline mode).",
+            year, day)))
+    }
+
+    fn download_description(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        Err(AoCError::IOError(format!(
+            "Puzzle description of {} day {} is not cached and downloading is disabled \
+            (offline mode).", year, day)))
+    }
+}
+
+/// Downloads the input from adventofcode.com using the configured session cookie.
+pub struct AocClientDownloader {
+    config: InputConfig,
+}
+
+impl AocClientDownloader {
+    pub fn new(config: InputConfig) -> Self {
+        Self { config }
+    }
+
+    fn get_client(&self, year: u16, day: u8) -> Result<AocClient, AoCError<String>> {
+        AocClient::builder()
+            .session_cookie(self.config.get_session_cookie()?)
+            .map_err(|e| AoCError::IOError(format!(
+                "Parsing session cookie failed: {}", e)))?
+            .year(year as i32)
+            .map_err(|e| AoCError::IOError(format!(
+                "Parsing year failed: {}", e)))?
+            .day(day as u32)
+            .map_err(|e| AoCError::IOError(format!(
+                "Parsing day failed: {}", e)))?
+            .build()
+            .map_err(|e| AoCError::IOError(format!(
+                "Building AocClient failed: {}", e)))
+    }
+}
+
+impl Downloader for AocClientDownloader {
+    fn download(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        self.get_client(year, day)?.get_input().map_err(|e| AoCError::IOError(format!(
+            "Retrieving puzzle input failed: {}", e)))
+    }
+
+    fn download_description(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        self.get_client(year, day)?.get_puzzle_html().map_err(|e| AoCError::IOError(format!(
+            "Retrieving puzzle description failed: {}", e)))
+    }
+}
+
+/// Copies the input from another directory, e.g. test fixtures or a shared input store.
+pub struct LocalDirectoryDownloader {
+    dir: PathBuf,
+    file_pattern: String,
+}
+
+impl LocalDirectoryDownloader {
+    pub fn new(dir: PathBuf, file_pattern: String) -> Self {
+        Self { dir, file_pattern }
+    }
+
+    fn get_config(&self) -> InputConfig {
+        InputConfig {
+            cache_dir: self.dir.clone(),
+            file_pattern: self.file_pattern.clone(),
+            ..InputConfig::default()
+        }
+    }
+}
+
+impl Downloader for LocalDirectoryDownloader {
+    fn download(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        let path = self.get_config().get_path(year, day)?;
+        fs::read_to_string(&path)
+            .map_err(|e| AoCError::IOError(format!(
+                "Reading input from '{}' failed: {}", path.display(), e)))
+    }
+
+    fn download_description(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+        let path = self.get_config().get_description_path(year, day)?;
+        fs::read_to_string(&path)
+            .map_err(|e| AoCError::IOError(format!(
+                "Reading puzzle description from '{}' failed: {}", path.display(), e)))
+    }
+}
+
+fn write_content_to_file(path: &Path, content: String) -> Result<(), AoCError<String>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| AoCError::IOError(format!(
+                "Creating folder structure '{:?}' failed. {}", parent, e)))?;
+    }
+
+    let mut file = File::create(path)
+        .map_err(|e| AoCError::IOError(format!(
+            "Opening file '{}' failed: {}", path.display(), e)))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| AoCError::IOError(format!(
+            "Writing to '{}' failed: {}", path.display(), e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_default_path() -> Result<(), AoCError<String>> {
+        let config = InputConfig::default();
+        let path = config.get_path(2016, 5)?;
+        assert!(path.ends_with("input/year_2016/input_day_05.txt"));
+        assert!(path.is_absolute());
+        assert!(config.get_path(2014, 5).is_err());
+        assert!(config.get_path(2016, 26).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_config_file() -> Result<(), AoCError<String>> {
+        let content = "[input]\n\
+            cache_dir = \"shared\"\n\
+            file_pattern = \"{year}/day_{day}.txt\"\n\
+            session_cookie_env = \"AOC_TEST_SESSION\"\n";
+        let config = InputConfig::default().with_config_file(content, Path::new("/etc/aoc"))?;
+        assert_eq!(config.get_path(2023, 7)?, PathBuf::from("/etc/aoc/shared/2023/day_7.txt"));
+        assert_eq!(config.session_cookie_env, "AOC_TEST_SESSION");
+        assert_eq!(config.session_cookie_file, InputConfig::default().session_cookie_file);
+
+        assert!(InputConfig::default().with_config_file("[input]\ncache = \"x\"", Path::new(""))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_env_overrides() -> Result<(), AoCError<String>> {
+        let content = "[input]\ncache_dir = \"/from/file\"\n";
+        let config = InputConfig::default()
+            .with_config_file(content, Path::new(""))?
+            .with_env(|name| match name {
+                INPUT_DIR_ENV_VAR => Some("/from/env".to_string()),
+                COOKIE_FILE_ENV_VAR => Some("/from/env/cookie".to_string()),
+                BACKEND_ENV_VAR => Some("offline".to_string()),
+                SERVER_URL_ENV_VAR => Some("http://localhost:8080".to_string()),
+                _ => None,
+            })?;
+        assert_eq!(config.get_path(2015, 12)?,
+                   PathBuf::from("/from/env/year_2015/input_day_12.txt"));
+        assert_eq!(config.session_cookie_file, PathBuf::from("/from/env/cookie"));
+        assert_eq!(config.backend, Backend::Offline);
+        assert_eq!(config.server_url, "http://localhost:8080");
+        Ok(())
+    }
+
+    #[test]
+    fn check_backend_parse() -> Result<(), AoCError<String>> {
+        assert_eq!(Backend::parse("aoc", Path::new("/base"))?, Backend::AocClient);
+        assert_eq!(Backend::parse("local:fixtures", Path::new("/base"))?,
+                   Backend::LocalDirectory(PathBuf::from("/base/fixtures")));
+        assert!(Backend::parse("online", Path::new("/base")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_profiles() -> Result<(), AoCError<String>> {
+        let content = "[input]\n\
+            cache_dir = \"/aoc\"\n\
+            backend = \"local:fixtures\"\n\
+            [input.profiles.alice]\n\
+            session_cookie_file = \"alice_cookie\"\n";
+        let config = InputConfig::default()
+            .with_config_file(content, Path::new("/etc/aoc"))?
+            .with_env(|name| match name {
+                PROFILES_ENV_VAR => Some("bob, alice".to_string()),
+                _ => None,
+            })?;
+        assert_eq!(config.get_profile_names(), vec!["alice", "bob"]);
+
+        let alice = config.get_profile("alice")?;
+        assert_eq!(alice.get_path(2023, 1)?,
+                   PathBuf::from("/aoc/alice/year_2023/input_day_01.txt"));
+        assert_eq!(alice.session_cookie_file, PathBuf::from("/etc/aoc/alice_cookie"));
+        assert_eq!(alice.backend,
+                   Backend::LocalDirectory(PathBuf::from("/etc/aoc/fixtures/alice")));
+        let carol = config.get_profile("carol-2")?;
+        assert_eq!(carol.session_cookie_file, PathBuf::from("/aoc/carol-2/session_cookie"));
+        assert_eq!(carol.session_cookie_env, "AOC_SESSION_COOKIE_CAROL_2");
+        assert!(config.get_profile("../bob").is_err());
+        Ok(())
+    }
+
+    fn get_temp_config(name: &str, backend: Backend) -> InputConfig {
+        let cache_dir = env::temp_dir().join(format!("aoc_input_{}", name));
+        let _ = fs::remove_dir_all(&cache_dir);
+        InputConfig { cache_dir, backend, ..InputConfig::default() }
+    }
+
+    #[test]
+    fn check_offline_backend() {
+        let config = get_temp_config("check_offline_backend", Backend::Offline);
+        let res = get_input_with_config(2016, 3, &config);
+        assert!(matches!(res, Err(AoCError::IOError(message)) if message.contains("offline")));
+    }
+
+    #[test]
+    fn check_local_directory_backend() -> Result<(), AoCError<String>> {
+        let fixtures = get_temp_config("check_local_directory_backend_fixtures", Backend::Offline);
+        write_content_to_file(&fixtures.get_path(2016, 3)?, "1 2 3\n4 5 6\n".to_string())?;
+        let backend = Backend::LocalDirectory(fixtures.cache_dir.clone());
+        let config = get_temp_config("check_local_directory_backend", backend);
+
+        let expected = vec!["1 2 3".to_string(), "4 5 6".to_string()];
+        assert_eq!(get_input_with_config(2016, 3, &config)?, expected);
+        assert!(config.get_path(2016, 3)?.exists());
+        assert!(get_input_with_config(2016, 4, &config).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_normalize_lines() {
+        let expected = vec!["  a b".to_string(), "".to_string(), "c  ".to_string()];
+        assert_eq!(normalize_lines("  a b\r\n\r\nc  \r\n\r\n\n"), expected);
+        assert_eq!(normalize_lines("  a b\n\nc  \r"), expected);
+        assert!(normalize_lines("\n\n").is_empty());
+    }
+
+    #[test]
+    fn check_normalize_lines_keeps_padding() {
+        let grid = "    |    \n +--+    \n         \n";
+        let lines = normalize_lines(grid);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "         ");
+        assert_eq!(normalize_lines("a\n   \r\n\n"), vec!["a".to_string(), "   ".to_string()]);
+    }
+
+    #[test]
+    fn check_error_pages() {
+        let login = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+        let html = "\n<!DOCTYPE html>\n<html lang=\"en-us\">\n</html>\n";
+        for content in [login, html, "\n"] {
+            assert!(matches!(parse_input(content, "Test"), Err(AoCError::IOError(_))));
+        }
+        assert!(parse_input("<<>>\n", "Test").is_ok());
+    }
+
+    #[test]
+    fn check_checksum() -> Result<(), AoCError<String>> {
+        let config = get_temp_config("check_checksum", Backend::Offline);
+        let html = ContentDownloader("<html><body>500 Internal Server Error</body></html>");
+        assert!(get_input_with_downloader(2017, 9, &config, &html).is_err());
+        assert!(!config.get_path(2017, 9)?.exists());
+
+        get_input_with_downloader(2017, 9, &config, &ContentDownloader("1\n2\n"))?;
+        let path = config.get_path(2017, 9)?;
+        assert_eq!(read_from_file(&get_checksum_path(&path))?, get_checksum("1\n2\n"));
+        write_content_to_file(&path, "1\n3\n".to_string())?;
+        assert!(get_input_with_config(2017, 9, &config).is_err());
+
+        fs::remove_file(get_checksum_path(&path))
+            .map_err(|e| AoCError::IOError(e.to_string()))?;
+        assert_eq!(get_input_with_config(2017, 9, &config)?, vec!["1", "3"]);
+        assert!(get_checksum_path(&path).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn check_description_cache() -> Result<(), AoCError<String>> {
+        let fixtures = get_temp_config("check_description_cache_fixtures", Backend::Offline);
+        let html = "<article><pre><code>1\n</code></pre></article>".to_string();
+        write_content_to_file(&fixtures.get_description_path(2023, 12)?, html.clone())?;
+        let downloader = LocalDirectoryDownloader::new(fixtures.cache_dir.clone(),
+                                                       fixtures.file_pattern.clone());
+        let config = get_temp_config("check_description_cache", Backend::Offline);
+
+        assert!(get_description_with_downloader(2023, 12, &config, &OfflineDownloader).is_err());
+        assert_eq!(get_description_with_downloader(2023, 12, &config, &downloader)?, html);
+        assert!(config.get_path(2023, 12)?.with_extension("html").exists());
+        assert_eq!(get_description_with_downloader(2023, 12, &config, &OfflineDownloader)?, html);
+        assert!(get_description_with_downloader(2023, 13, &config, &StaticDownloader).is_err());
+        Ok(())
+    }
+
+    struct StaticDownloader;
+
+    impl Downloader for StaticDownloader {
+        fn download(&self, year: u16, day: u8) -> Result<String, AoCError<String>> {
+            Ok(format!("{} {}", year, day))
+        }
+    }
+
+    struct ContentDownloader(&'static str);
+
+    impl Downloader for ContentDownloader {
+        fn download(&self, _: u16, _: u8) -> Result<String, AoCError<String>> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn check_custom_downloader() -> Result<(), AoCError<String>> {
+        let config = get_temp_config("check_custom_downloader", Backend::Offline);
+        assert_eq!(get_input_with_downloader(2017, 9, &config, &StaticDownloader)?,
+                   vec!["2017 9".to_string()]);
+        // now cached, the offline backend is not asked
+        assert_eq!(get_input_with_config(2017, 9, &config)?, vec!["2017 9".to_string()]);
+        Ok(())
+    }
+}
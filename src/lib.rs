@@ -1,6 +1,6 @@
-use std::fs::File;
+use std::fs;
 use std::io;
-use std::io::{BufRead, BufReader, stdin};
+use std::io::{BufRead, stdin};
 
 pub mod year_2015;
 pub mod year_2016;
@@ -8,30 +8,22 @@ pub mod year_2017;
 pub mod year_2023;
 pub mod year_2024;
 
-pub fn read_lines_trimmed_from_file(file_name: &str) -> io::Result<Vec<String>> {
-    let file = File::open(file_name)?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
+pub mod input;
+pub mod examples;
+pub mod parsing;
+pub mod solution;
+pub mod runner;
+pub mod answers;
+pub mod submit;
+pub mod scaffold;
 
-    let mut res = vec![];
-    for line in lines {
-        let line = line?;
-        res.push(String::from(line.trim()));
-    }
-    Ok(res)
+pub fn read_lines_trimmed_from_file(file_name: &str) -> io::Result<Vec<String>> {
+    let lines = read_lines_untrimmed_from_file(file_name)?;
+    Ok(lines.iter().map(|line| String::from(line.trim())).collect())
 }
 
 pub fn read_lines_untrimmed_from_file(file_name: &str) -> io::Result<Vec<String>> {
-    let file = File::open(file_name)?;
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
-
-    let mut res = vec![];
-    for line in lines {
-        let line = line?;
-        res.push(line);
-    }
-    Ok(res)
+    Ok(input::normalize_lines(&fs::read_to_string(file_name)?))
 }
 
 pub fn read_int_list_from_stdin() -> Vec<i32> {
@@ -92,10 +84,13 @@ pub mod output {
 pub mod errors {
     use std::error::Error;
     use std::fmt::{Debug, Display, Formatter};
+    use std::io;
+    use std::num::{ParseFloatError, ParseIntError};
+    use std::sync::Arc;
 
     pub type AoCResult<T> = Result<T, AoCError<String>>;
 
-    #[derive(Debug, PartialEq)]
+    #[derive(Debug, Clone, PartialEq)]
     pub enum AoCError<Message: Debug + Display> {
         UnexpectedInputLength(Message),
         BadInputFormat(Message),
@@ -103,6 +98,121 @@ pub mod errors {
         MultipleSolutionsFoundError(Message),
         MultithreadingError(Message),
         IOError(Message),
+        /// Another error together with where in the input it occurred.
+        WithContext(Box<AoCError<Message>>, Box<ErrorContext>),
+    }
+
+    /// Where in the input an error occurred and what led to it. All fields are optional.
+    #[derive(Debug, Clone, Default)]
+    pub struct ErrorContext {
+        pub year: Option<u16>,
+        pub day: Option<u8>,
+        /// Index of the line in the input (displayed 1-based).
+        pub line: Option<usize>,
+        /// Index of the char in the line (displayed 1-based).
+        pub column: Option<usize>,
+        /// The offending text, usually the whole line.
+        pub text: Option<String>,
+        /// Descriptions of the errors that led to this one, outermost first.
+        pub causes: Vec<String>,
+        /// The original error, returned by 'source()'. Shared to keep the error cloneable.
+        pub source: Option<Arc<dyn Error + Send + Sync>>,
+    }
+
+    impl PartialEq for ErrorContext {
+        /// Sources are compared by their description.
+        fn eq(&self, other: &Self) -> bool {
+            self.year == other.year && self.day == other.day && self.line == other.line &&
+                self.column == other.column && self.text == other.text &&
+                self.causes == other.causes &&
+                self.source.as_ref().map(|source| source.to_string()) ==
+                    other.source.as_ref().map(|source| source.to_string())
+        }
+    }
+
+    impl<Message: Debug + Display> AoCError<Message> {
+        /// Returns the error without its context.
+        pub fn get_inner(&self) -> &AoCError<Message> {
+            match self {
+                AoCError::WithContext(error, _) => error.get_inner(),
+                error => error,
+            }
+        }
+
+        pub fn get_context(&self) -> Option<&ErrorContext> {
+            match self {
+                AoCError::WithContext(_, context) => Some(context),
+                _ => None,
+            }
+        }
+
+        /// Adds context to the error, existing context is updated.
+        pub fn with_context<F: FnOnce(&mut ErrorContext)>(self, update: F) -> Self {
+            match self {
+                AoCError::WithContext(error, mut context) => {
+                    update(&mut context);
+                    AoCError::WithContext(error, context)
+                }
+                error => {
+                    let mut context = ErrorContext::default();
+                    update(&mut context);
+                    AoCError::WithContext(Box::new(error), Box::new(context))
+                }
+            }
+        }
+
+        pub fn with_day(self, year: u16, day: u8) -> Self {
+            self.with_context(|context| {
+                context.year = Some(year);
+                context.day = Some(day);
+            })
+        }
+
+        /// Marks the line with the given 'index' in the input as the cause of this error.
+        pub fn with_line(self, index: usize, text: &str) -> Self {
+            self.with_context(|context| {
+                context.line = Some(index);
+                context.text = Some(text.to_string());
+            })
+        }
+
+        /// Marks the char with the given 'index' in the offending line as the cause of this error.
+        pub fn with_column(self, index: usize) -> Self {
+            self.with_context(|context| context.column = Some(index))
+        }
+
+        /// Adds an error that led to this one.
+        pub fn with_cause<C: Display>(self, cause: C) -> Self {
+            self.with_context(|context| context.causes.push(cause.to_string()))
+        }
+
+        /// Keeps the original error as 'source()' of this one.
+        pub fn with_source<E: Error + Send + Sync + 'static>(self, source: E) -> Self {
+            self.with_context(|context| context.source = Some(Arc::new(source)))
+        }
+
+        /// Formats the error followed by the chain of its sources, one 'caused by' line each.
+        pub fn get_report(&self) -> String {
+            let mut report = self.to_string();
+            let mut source = self.source();
+            while let Some(error) = source {
+                report.push_str(&format!("\ncaused by: {}", error));
+                source = error.source();
+            }
+            report
+        }
+
+        pub fn get_variant_name(&self) -> &'static str {
+            match self {
+                AoCError::UnexpectedInputLength(_) => "UnexpectedInputLength",
+                AoCError::BadInputFormat(_) => "BadInputFormat",
+                AoCError::NoSolutionFoundError(_) => "NoSolutionFoundError",
+                AoCError::MultipleSolutionsFoundError(_) => "MultipleSolutionsFoundError",
+                AoCError::MultithreadingError(_) => "MultithreadingError",
+                AoCError::IOError(_) => "IOError",
+                AoCError::WithContext(error, _) => error.get_variant_name(),
+            }
+        }
     }
 
     impl<Message: Debug + Display> Display for AoCError<Message> {
@@ -127,11 +237,192 @@ pub mod errors {
                 AoCError::IOError(message) => {
                     write!(f, "Input/Output operation failed:\n{}", message)
                 }
+                AoCError::WithContext(error, context) => {
+                    write!(f, "{}{}", error, context)
+                }
             }
         }
     }
 
-    impl<Message: Debug + Display> Error for AoCError<Message> {}
+    impl Display for ErrorContext {
+        /// Formats the location and the offending text with a caret below the column:
+        /// ```text
+        ///  --> 2015 day 23, line 5, column 5
+        ///   |
+        /// 5 | jio x, +19
+        ///   |     ^
+        /// caused by: ..
+        /// ```
+        /// The source is left out, it is returned by 'source()' instead.
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut location = vec![];
+            if let (Some(year), Some(day)) = (self.year, self.day) {
+                location.push(format!("{} day {}", year, day));
+            }
+            if let Some(line) = self.line {
+                location.push(format!("line {}", line+1));
+            }
+            if let Some(column) = self.column {
+                location.push(format!("column {}", column+1));
+            }
+            if !location.is_empty() {
+                write!(f, "\n --> {}", location.join(", "))?;
+            }
+            if let Some(text) = &self.text {
+                let number = self.line.map(|line| (line+1).to_string()).unwrap_or_default();
+                let margin = " ".repeat(number.len());
+                write!(f, "\n{} |\n{} | {}", margin, number, text)?;
+                if let Some(column) = self.column {
+                    let offset = text.chars()
+                        .take(column)
+                        .map(|c| if c == '\t' { '\t' } else { ' ' })
+                        .collect::<String>();
+                    write!(f, "\n{} | {}^", margin, offset)?;
+                }
+            }
+            for cause in self.causes.iter() {
+                write!(f, "\ncaused by: {}", cause)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl AoCError<String> {
+        /// Replaces the message, the previous message becomes the first cause.
+        pub fn with_message<M: Display>(self, message: M) -> Self {
+            let (error, previous) = self.replace_message(message.to_string());
+            error.with_context(|context| context.causes.insert(0, previous))
+        }
+
+        fn replace_message(self, message: String) -> (Self, String) {
+            match self {
+                AoCError::UnexpectedInputLength(previous) =>
+                    (AoCError::UnexpectedInputLength(message), previous),
+                AoCError::BadInputFormat(previous) =>
+                    (AoCError::BadInputFormat(message), previous),
+                AoCError::NoSolutionFoundError(previous) =>
+                    (AoCError::NoSolutionFoundError(message), previous),
+                AoCError::MultipleSolutionsFoundError(previous) =>
+                    (AoCError::MultipleSolutionsFoundError(message), previous),
+                AoCError::MultithreadingError(previous) =>
+                    (AoCError::MultithreadingError(message), previous),
+                AoCError::IOError(previous) => (AoCError::IOError(message), previous),
+                AoCError::WithContext(error, context) => {
+                    let (error, previous) = error.replace_message(message);
+                    (AoCError::WithContext(Box::new(error), context), previous)
+                }
+            }
+        }
+    }
+
+    impl From<ParseIntError> for AoCError<String> {
+        fn from(error: ParseIntError) -> Self {
+            AoCError::BadInputFormat("Parsing integer failed.".to_string()).with_source(error)
+        }
+    }
+
+    impl From<ParseFloatError> for AoCError<String> {
+        fn from(error: ParseFloatError) -> Self {
+            AoCError::BadInputFormat("Parsing float failed.".to_string()).with_source(error)
+        }
+    }
+
+    impl From<io::Error> for AoCError<String> {
+        fn from(error: io::Error) -> Self {
+            AoCError::IOError(format!("{}.", error.kind())).with_source(error)
+        }
+    }
+
+    /// Adds context to any error convertible into an 'AoCError', e.g.
+    /// `words[1].parse::<i32>().context("Parsing speed failed.")?`.
+    pub trait ResultExt<T> {
+        /// Uses 'message' as message of the error, the previous message becomes its cause.
+        fn context<M: Display>(self, message: M) -> AoCResult<T>;
+
+        /// Same as 'context(..)', but only creates the message in case of an error.
+        fn context_with<M: Display, F: FnOnce() -> M>(self, message: F) -> AoCResult<T>;
+
+        /// Marks the line with the given 'index' in the input as the cause of the error.
+        fn at_line(self, index: usize, text: &str) -> AoCResult<T>;
+    }
+
+    impl<T, E: Into<AoCError<String>>> ResultExt<T> for Result<T, E> {
+        fn context<M: Display>(self, message: M) -> AoCResult<T> {
+            self.map_err(|e| e.into().with_message(message))
+        }
+
+        fn context_with<M: Display, F: FnOnce() -> M>(self, message: F) -> AoCResult<T> {
+            self.map_err(|e| e.into().with_message(message()))
+        }
+
+        fn at_line(self, index: usize, text: &str) -> AoCResult<T> {
+            self.map_err(|e| e.into().with_line(index, text))
+        }
+    }
+
+    impl<Message: Debug + Display> Error for AoCError<Message> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.get_context()
+                .and_then(|context| context.source.as_deref())
+                .map(|source| source as &(dyn Error + 'static))
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        fn parse_speed(word: &str) -> AoCResult<u32> {
+            Ok(word.parse::<u32>()?)
+        }
+
+        #[test]
+        fn check_conversions() {
+            let error = parse_speed("fast").expect_err("'fast' is no number");
+            assert_eq!(error.get_variant_name(), "BadInputFormat");
+            let source = error.source().expect("The ParseIntError is kept");
+            assert!(source.downcast_ref::<ParseIntError>().is_some());
+            assert_eq!(error.clone(), error);
+
+            let error = AoCError::from(io::Error::new(io::ErrorKind::NotFound, "no input"));
+            assert!(matches!(error.get_inner(), AoCError::IOError(_)));
+            assert_eq!(error.source().map(|source| source.to_string()),
+                       Some("no input".to_string()));
+        }
+
+        #[test]
+        fn check_context() {
+            let error = "1.5x".parse::<f64>()
+                .context("Parsing speed failed.")
+                .at_line(2, "Comet can fly 1.5x km/s")
+                .expect_err("'1.5x' is no float");
+            assert_eq!(error.get_inner(),
+                       &AoCError::BadInputFormat("Parsing speed failed.".to_string()));
+            assert_eq!(error.to_string(), "The input has unexpected input:\n\
+                Parsing speed failed.\n --> line 3\n  |\n3 | Comet can fly 1.5x km/s\n\
+                caused by: Parsing float failed.");
+            assert_eq!(error.get_report(), format!("{}\ncaused by: invalid float literal", error));
+        }
+
+        #[test]
+        fn check_source_chain() {
+            let error = AoCError::from(io::Error::new(io::ErrorKind::NotFound, "no input"))
+                .with_message("Reading the input failed.")
+                .with_line(0, "year_2015/input_day_01.txt");
+            let mut sources = vec![];
+            let mut source = error.source();
+            while let Some(cause) = source {
+                sources.push(cause.to_string());
+                source = cause.source();
+            }
+            assert_eq!(sources, vec!["no input"]);
+            assert!(!error.to_string().contains("no input"));
+            let report = error.get_report();
+            for cause in ["Reading the input failed.", "no input"] {
+                assert_eq!(report.matches(cause).count(), 1, "'{}' in '{}'", cause, report);
+            }
+        }
+    }
 }
 
 pub mod md5_collision {
@@ -348,98 +639,161 @@ pub mod string_manipulation {
     }
 }
 
-pub mod input {
-    use std::fs;
-    use std::fs::File;
-    use std::io::{BufRead, BufReader, Write};
-    use std::path::Path;
-    use aoc_client::AocClient;
-    use crate::errors::AoCError;
+pub mod geometrics {
+    use std::collections::{HashMap, VecDeque};
+    use std::fmt::{Display, Formatter};
+    use std::hash::Hash;
+    use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+    use std::slice::Iter;
+    use num::{CheckedAdd, CheckedMul, CheckedSub, NumCast, One, Signed, ToPrimitive, Zero};
+    use crate::errors::{AoCError, AoCResult, ResultExt};
 
-    pub fn get_input(year: u16, day: u8) -> Result<Vec<String>, AoCError<String>> {
-        let file_name = get_path(year, day)?;
-        let file = if let Ok(file) = File::open(&file_name) {
-            file
-        } else {
-            download(year, day)?;
-            File::open(&file_name)
-                .map_err(|e| AoCError::IOError(format!(
-                    "Opening just created file failed. {}", e)))?
-        };
-        read_from_file(file)
+    /// Point in the plane. For grids 'x' is the column and 'y' the row, growing southwards.
+    #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+    pub struct Point2D<I> {
+        pub x: I,
+        pub y: I,
     }
 
-    fn get_path(year: u16, day: u8) -> Result<String, AoCError<String>> {
-        if year < 2015 {
-            return Err(AoCError::IOError("AoC only started in 2015.".to_string()))
-        }
-        if day == 0 || day > 25 {
-            return Err(AoCError::IOError("Only days 1-25 supported.".to_string()))
+    impl<I> Point2D<I> {
+        pub fn new(x: I, y: I) -> Self {
+            Self { x, y }
         }
-        Ok(format!("input/year_{}/input_day_{:02}.txt", year, day))
     }
 
-    fn read_from_file(file: File) -> Result<Vec<String>, AoCError<String>> {
-        let reader = BufReader::new(file);
-        let lines = reader.lines();
-
-        let mut res = vec![];
-        for line in lines {
-            let line = line
-                .map_err(|e| AoCError::IOError(format!("Reading from file failed: {}", e)))?;
-            res.push(line);
+    impl<I: Copy + PartialOrd + Sub<Output = I>> Point2D<I> {
+        /// Returns the component wise absolute difference to 'other'. Works for unsigned types.
+        fn get_abs_diff(&self, other: &Self) -> Self {
+            let diff = |a: I, b: I| if a > b { a - b } else { b - a };
+            Self {
+                x: diff(self.x, other.x),
+                y: diff(self.y, other.y),
+            }
         }
-        Ok(res)
-    }
 
-    fn download(year: u16, day: u8) -> Result<(), AoCError<String>> {
-        let client = AocClient::builder()
-            .session_cookie_from_file("input/session_cookie")
-            .map_err(|e| AoCError::IOError(format!(
-                "Parsing session cookie failed: {}", e)))?
-            .year(year as i32)
-            .map_err(|e| AoCError::IOError(format!(
-                "Parsing year failed: {}", e)))?
-            .day(day as u32)
-            .map_err(|e| AoCError::IOError(format!(
-                "Parsing day failed: {}", e)))?
-            .build()
-            .map_err(|e| AoCError::IOError(format!(
-                "Building AocClient failed: {}", e)))?;
+        /// Returns the manhattan (taxicab) distance to 'other'.
+        pub fn manhattan(&self, other: &Self) -> I where I: Add<Output = I> {
+            let diff = self.get_abs_diff(other);
+            diff.x + diff.y
+        }
 
-        let input = client.get_input().map_err(|e| AoCError::IOError(format!(
-            "Retrieving puzzle input failed: {}", e)))?;
-        write_content_to_file(year, day, input)
+        /// Returns the chebyshev (chessboard) distance to 'other'.
+        pub fn chebyshev(&self, other: &Self) -> I {
+            let diff = self.get_abs_diff(other);
+            if diff.x > diff.y {
+                diff.x
+            } else {
+                diff.y
+            }
+        }
     }
 
-    fn write_content_to_file(year: u16, day: u8, content: String) -> Result<(), AoCError<String>> {
-        let path = get_path(year, day)?;
-
-        if let Some(parent) = Path::new(&path).parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| AoCError::IOError(format!(
-                    "Creating folder structure '{:?}' failed. {}", parent, e)))?;
+    impl<I: ToPrimitive> Point2D<I> {
+        /// Converts the point to another numeric type. Returns None if a coordinate does not fit.
+        pub fn cast<J: NumCast>(self) -> Option<Point2D<J>> {
+            Some(Point2D {
+                x: J::from(self.x)?,
+                y: J::from(self.y)?,
+            })
         }
-
-        let mut file = File::create(&path)
-            .map_err(|e| AoCError::IOError(format!(
-                "Opening file '{}' failed: {}", path, e)))?;
-        file.write_all(content.as_bytes())
-            .map_err(|e| AoCError::IOError(format!("Writing to '{}' failed: {}", path, e)))?;
-        Ok(())
     }
 
+    impl<I: CheckedMul> Point2D<I> {
+        pub fn checked_mul(&self, scalar: &I) -> Option<Self> {
+            Some(Self {
+                x: self.x.checked_mul(scalar)?,
+                y: self.y.checked_mul(scalar)?,
+            })
+        }
+    }
 
-}
+    impl<I> From<(I, I)> for Point2D<I> {
+        fn from((x, y): (I, I)) -> Self {
+            Self { x, y }
+        }
+    }
+    impl<I> From<Point2D<I>> for (I, I) {
+        fn from(point: Point2D<I>) -> Self {
+            (point.x, point.y)
+        }
+    }
 
-pub mod geometrics {
-    use std::fmt::{Display, Formatter};
-    use std::ops::{Add, AddAssign, Sub, SubAssign};
-    use std::slice::Iter;
-    use num::{CheckedAdd, CheckedSub, One, Zero};
-    use crate::errors::{AoCError, AoCResult};
+    impl<I: Display> Display for Point2D<I> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
 
-    pub type Point<I> = (I, I);
+    impl<I: Add<Output = I>> Add for Point2D<I> {
+        type Output = Point2D<I>;
+        fn add(self, rhs: Point2D<I>) -> Self::Output {
+            Self::Output {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+    impl<I: AddAssign> AddAssign for Point2D<I> {
+        fn add_assign(&mut self, rhs: Self) {
+            self.x += rhs.x;
+            self.y += rhs.y;
+        }
+    }
+    impl<I: CheckedAdd> CheckedAdd for Point2D<I> {
+        fn checked_add(&self, v: &Self) -> Option<Self> {
+            Some(Self {
+                x: self.x.checked_add(&v.x)?,
+                y: self.y.checked_add(&v.y)?,
+            })
+        }
+    }
+    impl<I: Sub<Output = I>> Sub for Point2D<I> {
+        type Output = Point2D<I>;
+        fn sub(self, rhs: Self) -> Self::Output {
+            Self {
+                x: self.x - rhs.x,
+                y: self.y - rhs.y,
+            }
+        }
+    }
+    impl<I: SubAssign> SubAssign for Point2D<I> {
+        fn sub_assign(&mut self, rhs: Self) {
+            self.x -= rhs.x;
+            self.y -= rhs.y;
+        }
+    }
+    impl<I: CheckedSub> CheckedSub for Point2D<I> {
+        fn checked_sub(&self, v: &Self) -> Option<Self> {
+            Some(Self {
+                x: self.x.checked_sub(&v.x)?,
+                y: self.y.checked_sub(&v.y)?,
+            })
+        }
+    }
+    impl<I: Neg<Output = I>> Neg for Point2D<I> {
+        type Output = Point2D<I>;
+        fn neg(self) -> Self::Output {
+            Self {
+                x: -self.x,
+                y: -self.y,
+            }
+        }
+    }
+    impl<I: Copy + Mul<Output = I>> Mul<I> for Point2D<I> {
+        type Output = Point2D<I>;
+        fn mul(self, rhs: I) -> Self::Output {
+            Self {
+                x: self.x * rhs,
+                y: self.y * rhs,
+            }
+        }
+    }
+    impl<I: Copy + MulAssign> MulAssign<I> for Point2D<I> {
+        fn mul_assign(&mut self, rhs: I) {
+            self.x *= rhs;
+            self.y *= rhs;
+        }
+    }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
     pub struct Point3D<I> {
@@ -461,8 +815,8 @@ pub mod geometrics {
     impl<I: AddAssign> AddAssign for Point3D<I> {
         fn add_assign(&mut self, rhs: Self) {
             self.x += rhs.x;
-            self.z += rhs.y;
-            self.y += rhs.z;
+            self.y += rhs.y;
+            self.z += rhs.z;
         }
     }
     impl<I: CheckedAdd> CheckedAdd for Point3D<I> {
@@ -522,26 +876,21 @@ pub mod geometrics {
             }
         }
 
-        pub fn move_point<I: Copy + CheckedSub + CheckedAdd + One>(&self, point: &Point<I>)
-            -> Option<Point<I>>
+        pub fn move_point<I: Copy + CheckedSub + CheckedAdd + One>(&self, point: &Point2D<I>)
+            -> Option<Point2D<I>>
         {
-            match self {
-                Direction::North => point.1.checked_sub(&I::one()).map(|y| (point.0, y)),
-                Direction::East => point.0.checked_add(&I::one()).map(|x| (x, point.1)),
-                Direction::South => point.1.checked_add(&I::one()).map(|y| (point.0, y)),
-                Direction::West => point.0.checked_sub(&I::one()).map(|x| (x, point.1)),
-            }
+            self.move_point_steps(point, I::one())
         }
 
         pub fn move_point_steps<I: Copy + CheckedSub + CheckedAdd>
-        (&self, point: &Point<I>, steps: I)
-            -> Option<Point<I>>
+        (&self, point: &Point2D<I>, steps: I)
+            -> Option<Point2D<I>>
         {
             match self {
-                Direction::North => point.1.checked_sub(&steps).map(|y| (point.0, y)),
-                Direction::East => point.0.checked_add(&steps).map(|x| (x, point.1)),
-                Direction::South => point.1.checked_add(&steps).map(|y| (point.0, y)),
-                Direction::West => point.0.checked_sub(&steps).map(|x| (x, point.1)),
+                Direction::North => point.y.checked_sub(&steps).map(|y| Point2D::new(point.x, y)),
+                Direction::East => point.x.checked_add(&steps).map(|x| Point2D::new(x, point.y)),
+                Direction::South => point.y.checked_add(&steps).map(|y| Point2D::new(point.x, y)),
+                Direction::West => point.x.checked_sub(&steps).map(|x| Point2D::new(x, point.y)),
             }
         }
 
@@ -608,8 +957,8 @@ pub mod geometrics {
             ]
         }
 
-        pub fn get_all_neighbors<I: Copy + CheckedAdd + CheckedSub + One>(point: &Point<I>)
-            -> Vec<Point<I>>
+        pub fn get_all_neighbors<I: Copy + CheckedAdd + CheckedSub + One>(point: &Point2D<I>)
+            -> Vec<Point2D<I>>
         {
             let dirs = Self::get_all_directions();
             dirs.into_iter()
@@ -654,6 +1003,180 @@ pub mod geometrics {
         }
     }
 
+    /// The four cardinal and the four diagonal directions.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    pub enum Direction8 {
+        North,
+        NorthEast,
+        East,
+        SouthEast,
+        South,
+        SouthWest,
+        West,
+        NorthWest,
+    }
+
+    impl Direction8 {
+        /// Returns the cardinal directions this direction is composed of.
+        pub fn get_components(&self) -> Vec<Direction> {
+            match self {
+                Direction8::North => vec![Direction::North],
+                Direction8::NorthEast => vec![Direction::North, Direction::East],
+                Direction8::East => vec![Direction::East],
+                Direction8::SouthEast => vec![Direction::South, Direction::East],
+                Direction8::South => vec![Direction::South],
+                Direction8::SouthWest => vec![Direction::South, Direction::West],
+                Direction8::West => vec![Direction::West],
+                Direction8::NorthWest => vec![Direction::North, Direction::West],
+            }
+        }
+
+        /// Returns the offset of a single step, 'y' grows southwards.
+        pub fn get_offset<I: Signed>(&self) -> Point2D<I> {
+            let mut offset = Point2D::new(I::zero(), I::zero());
+            for dir in self.get_components() {
+                match dir {
+                    Direction::North => offset.y = offset.y - I::one(),
+                    Direction::East => offset.x = offset.x + I::one(),
+                    Direction::South => offset.y = offset.y + I::one(),
+                    Direction::West => offset.x = offset.x - I::one(),
+                }
+            }
+            offset
+        }
+
+        pub fn move_point<I: Copy + CheckedSub + CheckedAdd + One>(&self, point: &Point2D<I>)
+            -> Option<Point2D<I>>
+        {
+            self.get_components().iter()
+                .try_fold(*point, |point, dir| dir.move_point(&point))
+        }
+
+        /// Returns the direction turned 45 degrees clockwise.
+        pub fn get_right(&self) -> Self {
+            match self {
+                Direction8::North => Self::NorthEast,
+                Direction8::NorthEast => Self::East,
+                Direction8::East => Self::SouthEast,
+                Direction8::SouthEast => Self::South,
+                Direction8::South => Self::SouthWest,
+                Direction8::SouthWest => Self::West,
+                Direction8::West => Self::NorthWest,
+                Direction8::NorthWest => Self::North,
+            }
+        }
+
+        /// Returns the direction turned 45 degrees counterclockwise.
+        pub fn get_left(&self) -> Self {
+            self.get_opposing().get_right().get_right().get_right()
+        }
+
+        pub fn get_opposing(&self) -> Self {
+            self.get_right().get_right().get_right().get_right()
+        }
+
+        pub fn is_diagonal(&self) -> bool {
+            self.get_components().len() == 2
+        }
+
+        pub fn get_all_directions() -> Vec<Self> {
+            vec![
+                Self::North,
+                Self::NorthEast,
+                Self::East,
+                Self::SouthEast,
+                Self::South,
+                Self::SouthWest,
+                Self::West,
+                Self::NorthWest,
+            ]
+        }
+
+        pub fn get_all_neighbors<I: Copy + CheckedAdd + CheckedSub + One>(point: &Point2D<I>)
+            -> Vec<Point2D<I>>
+        {
+            Self::get_all_directions().into_iter()
+                .filter_map(|dir| dir.move_point(point))
+                .collect()
+        }
+    }
+
+    impl From<Direction> for Direction8 {
+        fn from(dir: Direction) -> Self {
+            match dir {
+                Direction::North => Self::North,
+                Direction::East => Self::East,
+                Direction::South => Self::South,
+                Direction::West => Self::West,
+            }
+        }
+    }
+
+    impl Display for Direction8 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Direction8::North => write!(f, "North"),
+                Direction8::NorthEast => write!(f, "NorthEast"),
+                Direction8::East => write!(f, "East"),
+                Direction8::SouthEast => write!(f, "SouthEast"),
+                Direction8::South => write!(f, "South"),
+                Direction8::SouthWest => write!(f, "SouthWest"),
+                Direction8::West => write!(f, "West"),
+                Direction8::NorthWest => write!(f, "NorthWest"),
+            }
+        }
+    }
+
+    /// Positions considered adjacent to a position.
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub enum Neighborhood {
+        /// The four orthogonal neighbors.
+        VonNeumann,
+        /// The four orthogonal and the four diagonal neighbors.
+        Moore,
+        /// Any offsets relative to the position.
+        Custom(Vec<Point2D<isize>>),
+    }
+
+    impl Neighborhood {
+        pub fn get_offsets(&self) -> Vec<Point2D<isize>> {
+            match self {
+                Neighborhood::VonNeumann => Direction::get_all_directions().into_iter()
+                    .map(|dir| Direction8::from(dir).get_offset())
+                    .collect(),
+                Neighborhood::Moore => Direction8::get_all_directions().iter()
+                    .map(|dir| dir.get_offset())
+                    .collect(),
+                Neighborhood::Custom(offsets) => offsets.clone(),
+            }
+        }
+
+        /// Returns all neighbors of 'point' inside an area of size 'dimension' starting at the
+        /// origin.
+        pub fn get_neighbors(&self, point: &Point2D<usize>, dimension: &Point2D<usize>)
+            -> Vec<Point2D<usize>>
+        {
+            self.get_offsets().into_iter()
+                .filter_map(|offset| {
+                    let x = point.x.checked_add_signed(offset.x)?;
+                    let y = point.y.checked_add_signed(offset.y)?;
+                    (x < dimension.x && y < dimension.y).then_some(Point2D::new(x, y))
+                })
+                .collect()
+        }
+
+        /// Returns all neighbors of 'point' in the unbounded plane. Neighbors outside the range
+        /// of 'I' are skipped.
+        pub fn get_all_neighbors<I: Copy + Signed + NumCast + CheckedAdd>(&self, point: &Point2D<I>)
+            -> Vec<Point2D<I>>
+        {
+            self.get_offsets().into_iter()
+                .filter_map(|offset| offset.cast::<I>())
+                .filter_map(|offset| point.checked_add(&offset))
+                .collect()
+        }
+    }
+
     pub enum Direction3D {
         XUp,
         XDown,
@@ -742,23 +1265,23 @@ pub mod geometrics {
                 .map(|row| GridRowIter { iter: row.iter() })
         }
 
-        pub fn get_tile(&self, pos: &Point<usize>) -> Option<&T> {
-            if let Some(row) = self.grid.get(pos.1) {
-                row.get(pos.0)
+        pub fn get_tile(&self, pos: &Point2D<usize>) -> Option<&T> {
+            if let Some(row) = self.grid.get(pos.y) {
+                row.get(pos.x)
             } else {
                 None
             }
         }
 
-        pub fn get_tile_mut(&mut self, pos: &Point<usize>) -> Option<&mut T> {
-            if let Some(row) = self.grid.get_mut(pos.1) {
-                row.get_mut(pos.0)
+        pub fn get_tile_mut(&mut self, pos: &Point2D<usize>) -> Option<&mut T> {
+            if let Some(row) = self.grid.get_mut(pos.y) {
+                row.get_mut(pos.x)
             } else {
                 None
             }
         }
 
-        pub fn set_tile(&mut self, pos: &Point<usize>, tile: T) -> bool {
+        pub fn set_tile(&mut self, pos: &Point2D<usize>, tile: T) -> bool {
             if let Some(prev) = self.get_tile_mut(pos) {
                 *prev = tile;
                 true
@@ -767,17 +1290,30 @@ pub mod geometrics {
             }
         }
 
-        pub fn get_dimension(&self) -> Point<usize> {
+        /// Returns the width as 'x' and the height as 'y'.
+        pub fn get_dimension(&self) -> Point2D<usize> {
             if self.grid.is_empty() {
-                return (0, 0)
+                return Point2D::new(0, 0)
             }
-            (self.grid[0].len(), self.grid.len())
+            Point2D::new(self.grid[0].len(), self.grid.len())
+        }
+
+        /// Returns all positions of the 'neighborhood' of 'pos' which are inside the grid.
+        pub fn get_neighbors(&self, pos: &Point2D<usize>, neighborhood: &Neighborhood)
+            -> Vec<Point2D<usize>>
+        {
+            neighborhood.get_neighbors(pos, &self.get_dimension())
+        }
+
+        /// Returns a view repeating the grid infinitely in all directions.
+        pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+            WrappingGrid { grid: self }
         }
     }
 
     impl<T: Clone> Grid<T> {
-        pub fn new(dimension: Point<usize>, default: T) -> Grid<T> {
-            let grid = vec![vec![default; dimension.0]; dimension.1];
+        pub fn new(dimension: Point2D<usize>, default: T) -> Grid<T> {
+            let grid = vec![vec![default; dimension.x]; dimension.y];
             Self { grid }
         }
     }
@@ -803,8 +1339,8 @@ pub mod geometrics {
             }
             // Northern and southern edge
             for x in 0..self.grid[0].len() {
-                let northern = (x, 0);
-                let southern = (x, self.grid.len()-1);
+                let northern = Point2D::new(x, 0);
+                let southern = Point2D::new(x, self.grid.len()-1);
                 if self.get_tile(&northern) == Some(to_overwrite) {
                     //self.mark_all_connected(to_overwrite, template.clone(), &northern);
                     self.mark_all_connected(to_overwrite, template.clone(), &northern);
@@ -816,8 +1352,8 @@ pub mod geometrics {
             }
             // Eastern and western edge
             for y in 0..self.grid.len() {
-                let eastern = (self.grid[y].len()-1, y);
-                let western = (0, y);
+                let eastern = Point2D::new(self.grid[y].len()-1, y);
+                let western = Point2D::new(0, y);
                 if self.get_tile(&eastern) == Some(to_overwrite) {
                     //self.mark_all_connected(to_overwrite, template.clone(), &eastern);
                     self.mark_all_connected(to_overwrite, template.clone(), &eastern);
@@ -831,7 +1367,7 @@ pub mod geometrics {
 
         /// Finds all tiles equal to 'to_overwrite' reachable from the given Point 'pos' and
         /// replaces them using 'template' tiles
-        fn mark_all_connected(&mut self, to_overwrite: &T, template: T, pos: &Point<usize>) {
+        fn mark_all_connected(&mut self, to_overwrite: &T, template: T, pos: &Point2D<usize>) {
             let mut list = vec![*pos];
             while let Some(next) = list.pop() {
                 if let Some(tile) = self.get_tile_mut(&next) {
@@ -847,12 +1383,12 @@ pub mod geometrics {
     }
 
     impl<T: Eq> Grid<T> {
-        pub fn get_all_positions_of(&self, pattern: &T) -> Vec<Point<usize>> {
+        pub fn get_all_positions_of(&self, pattern: &T) -> Vec<Point2D<usize>> {
             let mut res = vec![];
-            for (row_index, row) in self.grid.iter().enumerate() {
-                for (col_index, elem) in row.iter().enumerate() {
+            for (y, row) in self.grid.iter().enumerate() {
+                for (x, elem) in row.iter().enumerate() {
                     if elem == pattern {
-                        res.push((row_index, col_index));
+                        res.push(Point2D::new(x, y));
                     }
                 }
             }
@@ -908,6 +1444,29 @@ pub mod geometrics {
         }
     }
 
+    impl<T: ParsableToken> Grid<T> {
+        /// Parses a grid of whitespace separated tokens, e.g. multi-digit cells.
+        pub fn parse_tokens(input: &[String]) -> AoCResult<Grid<T>> {
+            if input.is_empty() {
+                return Err(AoCError::UnexpectedInputLength("Input cannot be empty".to_string()))
+            }
+            let mut grid: Vec<Vec<T>> = Vec::with_capacity(input.len());
+            for (index, line) in input.iter().enumerate() {
+                let row = line.split_whitespace()
+                    .map(|token| T::parse_token(token))
+                    .collect::<AoCResult<Vec<_>>>()
+                    .at_line(index, line)?;
+                if !grid.is_empty() && grid[0].len() != row.len() {
+                    return Err(AoCError::BadInputFormat(
+                        "Lines need to have the same number of tokens".to_string())
+                        .with_line(index, line))
+                }
+                grid.push(row);
+            }
+            Ok(Self { grid })
+        }
+    }
+
     impl<T: Display> Display for Grid<T> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
             for line in self.grid.iter() {
@@ -944,9 +1503,493 @@ pub mod geometrics {
         }
     }
 
+    /// View of a grid repeated infinitely in all directions. The original grid is the copy (0, 0),
+    /// its tile (0, 0) is at the origin.
+    pub struct WrappingGrid<'a, T> {
+        grid: &'a Grid<T>,
+    }
+
+    impl<'a, T> WrappingGrid<'a, T> {
+        pub fn new(grid: &'a Grid<T>) -> Self {
+            Self { grid }
+        }
+
+        /// Returns the position inside the original grid 'pos' is a copy of. None if the grid is
+        /// empty.
+        pub fn get_inner_position(&self, pos: &Point2D<i64>) -> Option<Point2D<usize>> {
+            let dimension = self.get_signed_dimension()?;
+            Some(Point2D::new(pos.x.rem_euclid(dimension.x) as usize,
+                              pos.y.rem_euclid(dimension.y) as usize))
+        }
+
+        /// Returns the copy of the grid 'pos' is in, e.g. (-1, 0) for the copy west of the
+        /// original grid. None if the grid is empty.
+        pub fn get_copy(&self, pos: &Point2D<i64>) -> Option<Point2D<i64>> {
+            let dimension = self.get_signed_dimension()?;
+            Some(Point2D::new(pos.x.div_euclid(dimension.x), pos.y.div_euclid(dimension.y)))
+        }
+
+        pub fn get_tile(&self, pos: &Point2D<i64>) -> Option<&'a T> {
+            self.grid.get_tile(&self.get_inner_position(pos)?)
+        }
+
+        /// Returns all positions of the 'neighborhood' of 'pos'. None are returned if the grid is
+        /// empty.
+        pub fn get_neighbors(&self, pos: &Point2D<i64>, neighborhood: &Neighborhood)
+            -> Vec<Point2D<i64>>
+        {
+            if self.get_signed_dimension().is_none() {
+                return vec![]
+            }
+            neighborhood.get_all_neighbors(pos)
+        }
+
+        fn get_signed_dimension(&self) -> Option<Point2D<i64>> {
+            let dimension = self.grid.get_dimension();
+            if dimension.x == 0 || dimension.y == 0 {
+                return None
+            }
+            dimension.cast()
+        }
+    }
+
+    /// Common interface of 'Grid' and 'WrappingGrid' used by the path finding utilities.
+    pub trait GridView {
+        type Position: Copy + Eq + Hash;
+        type Tile;
+
+        fn get_tile(&self, pos: &Self::Position) -> Option<&Self::Tile>;
+
+        fn get_neighbors(&self, pos: &Self::Position, neighborhood: &Neighborhood)
+            -> Vec<Self::Position>;
+
+        /// Breadth first search from 'start', entering only tiles for which 'passable' holds.
+        /// Returns the number of steps to every position reachable in at most 'max_steps' steps.
+        fn get_distances<F: Fn(&Self::Tile) -> bool>(&self, start: Self::Position,
+                                                     neighborhood: &Neighborhood,
+                                                     max_steps: usize, passable: F)
+            -> HashMap<Self::Position, usize>
+        {
+            let mut distances = HashMap::from([(start, 0)]);
+            let mut queue = VecDeque::from([(start, 0)]);
+            while let Some((pos, steps)) = queue.pop_front() {
+                if steps >= max_steps {
+                    continue
+                }
+                for neighbor in self.get_neighbors(&pos, neighborhood) {
+                    if distances.contains_key(&neighbor) ||
+                        !self.get_tile(&neighbor).is_some_and(&passable)
+                    {
+                        continue
+                    }
+                    distances.insert(neighbor, steps+1);
+                    queue.push_back((neighbor, steps+1));
+                }
+            }
+            distances
+        }
+    }
+
+    impl<T> GridView for Grid<T> {
+        type Position = Point2D<usize>;
+        type Tile = T;
+
+        fn get_tile(&self, pos: &Self::Position) -> Option<&T> {
+            Grid::get_tile(self, pos)
+        }
+
+        fn get_neighbors(&self, pos: &Self::Position, neighborhood: &Neighborhood)
+            -> Vec<Self::Position>
+        {
+            Grid::get_neighbors(self, pos, neighborhood)
+        }
+    }
+
+    impl<T> GridView for WrappingGrid<'_, T> {
+        type Position = Point2D<i64>;
+        type Tile = T;
+
+        fn get_tile(&self, pos: &Self::Position) -> Option<&T> {
+            WrappingGrid::get_tile(self, pos)
+        }
+
+        fn get_neighbors(&self, pos: &Self::Position, neighborhood: &Neighborhood)
+            -> Vec<Self::Position>
+        {
+            WrappingGrid::get_neighbors(self, pos, neighborhood)
+        }
+    }
+
+    /// Unbounded grid with signed coordinates. Only tiles differing from the default tile are
+    /// stored.
+    #[derive(Debug, Clone)]
+    pub struct SparseGrid<T> {
+        tiles: HashMap<Point2D<i64>, T>,
+        default: T,
+        bounds: Option<(Point2D<i64>, Point2D<i64>)>,
+    }
+
+    impl<T> SparseGrid<T> {
+        pub fn new(default: T) -> Self {
+            Self {
+                tiles: HashMap::new(),
+                default,
+                bounds: None,
+            }
+        }
+
+        /// Returns the tile at 'pos', the default tile if it was never set.
+        pub fn get_tile(&self, pos: &Point2D<i64>) -> &T {
+            self.tiles.get(pos).unwrap_or(&self.default)
+        }
+
+        /// Returns the smallest and the largest corner of the area containing all tiles set so
+        /// far, including tiles reset to the default tile since. None if no tile was set.
+        pub fn get_bounds(&self) -> Option<(Point2D<i64>, Point2D<i64>)> {
+            self.bounds
+        }
+
+        /// Returns the number of stored tiles, i.e. tiles differing from the default tile.
+        pub fn len(&self) -> usize {
+            self.tiles.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.tiles.is_empty()
+        }
+
+        /// Iterates all stored tiles in arbitrary order.
+        pub fn iter(&self) -> impl Iterator<Item = (&Point2D<i64>, &T)> {
+            self.tiles.iter()
+        }
+
+        fn extend_bounds(&mut self, pos: &Point2D<i64>) {
+            self.bounds = Some(match self.bounds {
+                None => (*pos, *pos),
+                Some((min, max)) => (
+                    Point2D::new(min.x.min(pos.x), min.y.min(pos.y)),
+                    Point2D::new(max.x.max(pos.x), max.y.max(pos.y)),
+                ),
+            });
+        }
+    }
+
+    impl<T: PartialEq> SparseGrid<T> {
+        /// Sets the tile at 'pos'. Setting the default tile removes the stored tile.
+        pub fn set_tile(&mut self, pos: &Point2D<i64>, tile: T) {
+            self.extend_bounds(pos);
+            if tile == self.default {
+                self.tiles.remove(pos);
+            } else {
+                self.tiles.insert(*pos, tile);
+            }
+        }
+    }
+
+    impl<T: Clone + PartialEq> SparseGrid<T> {
+        /// Copies all tiles of 'grid', its tile (0, 0) is placed at 'origin'.
+        pub fn from_grid(grid: &Grid<T>, default: T, origin: Point2D<i64>) -> Self {
+            let mut sparse = Self::new(default);
+            for (y, row) in grid.grid.iter().enumerate() {
+                for (x, tile) in row.iter().enumerate() {
+                    let pos = origin + Point2D::new(x as i64, y as i64);
+                    sparse.set_tile(&pos, tile.clone());
+                }
+            }
+            sparse
+        }
+    }
+
+    impl<T: Clone> SparseGrid<T> {
+        /// Returns a dense grid covering 'get_bounds()' and the position of its tile (0, 0).
+        pub fn to_grid(&self) -> (Grid<T>, Point2D<i64>) {
+            let Some((min, max)) = self.bounds else {
+                return (Grid { grid: vec![] }, Point2D::new(0, 0))
+            };
+            let dimension = Point2D::new((max.x-min.x+1) as usize, (max.y-min.y+1) as usize);
+            let mut grid = Grid::new(dimension, self.default.clone());
+            for (pos, tile) in self.tiles.iter() {
+                let relative = (*pos - min).cast::<usize>()
+                    .expect("Stored tiles are inside the bounds");
+                grid.set_tile(&relative, tile.clone());
+            }
+            (grid, min)
+        }
+    }
+
+    impl<T: Display> Display for SparseGrid<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let Some((min, max)) = self.bounds else {
+                return Ok(())
+            };
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    write!(f, "{}", self.get_tile(&Point2D::new(x, y)))?;
+                }
+                writeln!(f)?;
+            }
+            Ok(())
+        }
+    }
+
     pub trait Parsable {
         fn parse(c: char) -> AoCResult<Self> where Self: Sized;
     }
+
+    /// Parsing of a tile from a token of any length. Implemented for all 'Parsable' tiles (tokens
+    /// of a single char) and the integer types.
+    pub trait ParsableToken {
+        fn parse_token(token: &str) -> AoCResult<Self> where Self: Sized;
+    }
+
+    impl<T: Parsable> ParsableToken for T {
+        fn parse_token(token: &str) -> AoCResult<Self> {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => T::parse(c),
+                _ => Err(AoCError::BadInputFormat(format!(
+                    "Expected a single char as tile. Found '{}'", token))),
+            }
+        }
+    }
+
+    macro_rules! impl_parsable_token_for_integers {
+        ($($int:ty),*) => {$(
+            impl ParsableToken for $int {
+                fn parse_token(token: &str) -> AoCResult<Self> {
+                    Ok(token.parse::<$int>()?)
+                }
+            }
+        )*}
+    }
+    impl_parsable_token_for_integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+    /// Parsing of an entity described by a whole input line.
+    pub trait ParsableLine {
+        fn parse_line(line: &str) -> AoCResult<Self> where Self: Sized;
+
+        /// Parses every line of the input. Errors contain the line they occurred in.
+        fn parse_lines(input: &[String]) -> AoCResult<Vec<Self>> where Self: Sized {
+            input.iter()
+                .enumerate()
+                .map(|(index, line)| Self::parse_line(line).at_line(index, line))
+                .collect()
+        }
+    }
+
+    /// Implements 'Parsable' for a tile type using a table of chars and the tiles they stand for.
+    /// ```ignore
+    /// impl_parsable!(Tile {
+    ///     '#' => Tile::Wall,
+    ///     '<' => Tile::Slope(Direction::West),
+    /// });
+    /// ```
+    macro_rules! impl_parsable {
+        ($tile:ty { $($char:literal => $value:expr),+ $(,)? }) => {
+            impl $crate::geometrics::Parsable for $tile {
+                fn parse(c: char) -> $crate::errors::AoCResult<Self> {
+                    match c {
+                        $($char => Ok($value),)+
+                        c => Err($crate::errors::AoCError::BadInputFormat(format!(
+                            "Parsing {} failed, expected one of {}. Found '{}'",
+                            stringify!($tile), [$(format!("'{}'", $char)),+].join(", "), c))),
+                    }
+                }
+            }
+        };
+    }
+    pub(crate) use impl_parsable;
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+        enum Tile {
+            Wall,
+            Slope(Direction),
+        }
+
+        impl_parsable!(Tile {
+            '#' => Tile::Wall,
+            '>' => Tile::Slope(Direction::East),
+        });
+
+        struct Move(Direction, usize);
+
+        impl ParsableLine for Move {
+            fn parse_line(line: &str) -> AoCResult<Self> {
+                let (direction, steps) = line.split_once(' ')
+                    .ok_or_else(|| AoCError::BadInputFormat("Missing steps".to_string()))?;
+                let direction = match direction {
+                    "R" => Direction::East,
+                    "L" => Direction::West,
+                    _ => return Err(AoCError::BadInputFormat("Bad direction".to_string())),
+                };
+                Ok(Self(direction, steps.parse()?))
+            }
+        }
+
+        fn get_lines(input: &str) -> Vec<String> {
+            input.lines().map(|line| line.to_string()).collect()
+        }
+
+        #[test]
+        fn check_point_3d() {
+            let mut point = Point3D { x: 1, y: 2, z: 3 };
+            point += Point3D { x: 10, y: 20, z: 30 };
+            assert_eq!(point, Point3D { x: 11, y: 22, z: 33 });
+        }
+
+        #[test]
+        fn check_point_2d() {
+            let p0 = Point2D::new(3, -2);
+            let p1 = Point2D::from((-1, 4));
+            assert_eq!(p0 + p1, Point2D::new(2, 2));
+            assert_eq!(p0 - p1, Point2D::new(4, -6));
+            assert_eq!(-p0, Point2D::new(-3, 2));
+            assert_eq!(p0 * 3, Point2D::new(9, -6));
+            assert_eq!(p0.manhattan(&p1), 10);
+            assert_eq!(p0.chebyshev(&p1), 6);
+            assert_eq!(Point2D::new(2u8, 7).manhattan(&Point2D::new(5, 1)), 9);
+            assert_eq!(Point2D::new(0u8, 1).checked_sub(&Point2D::new(1, 0)), None);
+            assert_eq!(Point2D::new(100u8, 1).checked_mul(&3), None);
+            assert_eq!(p1.cast::<usize>(), None);
+            assert_eq!(p0.cast::<i64>(), Some(Point2D::new(3i64, -2)));
+            assert_eq!(<(i32, i32)>::from(p0), (3, -2));
+        }
+
+        #[test]
+        fn check_direction_8() {
+            let origin = Point2D::new(0usize, 0);
+            assert_eq!(Direction8::SouthEast.move_point(&origin), Some(Point2D::new(1, 1)));
+            assert_eq!(Direction8::NorthEast.move_point(&origin), None);
+            assert_eq!(Direction8::NorthWest.get_offset::<i32>(), Point2D::new(-1, -1));
+            assert_eq!(Direction8::North.get_left(), Direction8::NorthWest);
+            assert_eq!(Direction8::SouthWest.get_opposing(), Direction8::NorthEast);
+            assert_eq!(Direction8::get_all_neighbors(&origin).len(), 3);
+            assert!(Direction8::SouthWest.is_diagonal());
+            assert!(!Direction8::West.is_diagonal());
+        }
+
+        #[test]
+        fn check_neighborhood() {
+            let grid = Grid::new(Point2D::new(3, 2), 0u8);
+            let corner = Point2D::new(2, 1);
+            assert_eq!(grid.get_neighbors(&corner, &Neighborhood::VonNeumann),
+                       vec![Point2D::new(2, 0), Point2D::new(1, 1)]);
+            assert_eq!(grid.get_neighbors(&corner, &Neighborhood::Moore).len(), 3);
+            assert_eq!(grid.get_neighbors(&Point2D::new(1, 0), &Neighborhood::Moore).len(), 5);
+            let knight = Neighborhood::Custom(vec![Point2D::new(-2, 1), Point2D::new(1, 2)]);
+            assert_eq!(grid.get_neighbors(&corner, &knight), vec![]);
+            assert_eq!(grid.get_neighbors(&Point2D::new(2, 0), &knight),
+                       vec![Point2D::new(0, 1)]);
+            assert_eq!(Neighborhood::Moore.get_all_neighbors(&Point2D::new(0i32, 0)).len(), 8);
+            assert_eq!(Neighborhood::Moore.get_all_neighbors(&Point2D::new(i8::MAX, 0)).len(), 5);
+            assert_eq!(Neighborhood::VonNeumann.get_all_neighbors(&Point2D::new(i8::MIN, i8::MIN)),
+                       vec![Point2D::new(i8::MIN+1, i8::MIN), Point2D::new(i8::MIN, i8::MIN+1)]);
+        }
+
+        #[test]
+        fn check_sparse_grid() {
+            let mut sparse = SparseGrid::new('.');
+            assert!(sparse.get_bounds().is_none());
+            assert_eq!(sparse.to_string(), "");
+            sparse.set_tile(&Point2D::new(-2, 1), '#');
+            sparse.set_tile(&Point2D::new(1, -1), '#');
+            sparse.set_tile(&Point2D::new(0, 0), '.');
+            assert_eq!(sparse.len(), 2);
+            assert_eq!(sparse.get_tile(&Point2D::new(-2, 1)), &'#');
+            assert_eq!(sparse.get_tile(&Point2D::new(100, -100)), &'.');
+            assert_eq!(sparse.get_bounds(), Some((Point2D::new(-2, -1), Point2D::new(1, 1))));
+            assert_eq!(sparse.to_string(), "...#\n....\n#...\n");
+
+            let (grid, origin) = sparse.to_grid();
+            assert_eq!(origin, Point2D::new(-2, -1));
+            assert_eq!(grid.get_tile(&Point2D::new(3, 0)), Some(&'#'));
+            let copy = SparseGrid::from_grid(&grid, '.', origin);
+            assert_eq!(copy.to_string(), sparse.to_string());
+            assert_eq!(copy.len(), 2);
+        }
+
+        #[test]
+        fn check_wrapping_grid() -> AoCResult<()> {
+            let grid = Grid::<Tile>::parse(&get_lines("#>>"))?;
+            let wrapping = grid.wrapping();
+            let pos = Point2D::new(-2, 5);
+            assert_eq!(wrapping.get_inner_position(&pos), Some(Point2D::new(1, 0)));
+            assert_eq!(wrapping.get_copy(&pos), Some(Point2D::new(-1, 5)));
+            assert_eq!(wrapping.get_copy(&Point2D::new(2, 0)), Some(Point2D::new(0, 0)));
+            assert_eq!(wrapping.get_tile(&Point2D::new(-3, -1)), Some(&Tile::Wall));
+            assert_eq!(wrapping.get_neighbors(&pos, &Neighborhood::Moore).len(), 8);
+
+            // columns 1 and 2 of each copy form infinite corridors
+            let start = Point2D::new(1, 0);
+            let distances = wrapping.get_distances(start, &Neighborhood::VonNeumann, 4,
+                                                   |tile| tile != &Tile::Wall);
+            assert_eq!(distances.get(&Point2D::new(2, -3)), Some(&4));
+            assert_eq!(distances.get(&Point2D::new(3, 0)), None);
+            assert_eq!(distances.len(), 16);
+            let distances = grid.get_distances(Point2D::new(1, 0), &Neighborhood::VonNeumann, 4,
+                                               |tile| tile != &Tile::Wall);
+            assert_eq!(distances.len(), 2);
+
+            let empty = Grid::new(Point2D::new(0, 0), Tile::Wall);
+            assert_eq!(empty.wrapping().get_tile(&Point2D::new(0, 0)), None);
+            Ok(())
+        }
+
+        #[test]
+        fn check_get_all_positions_of() -> AoCResult<()> {
+            let grid = Grid::<Tile>::parse(&get_lines("##>\n###"))?;
+            let positions = grid.get_all_positions_of(&Tile::Slope(Direction::East));
+            assert_eq!(positions, vec![Point2D::new(2, 0)]);
+            assert_eq!(grid.get_tile(&positions[0]), Some(&Tile::Slope(Direction::East)));
+            assert_eq!(grid.get_dimension(), Point2D::new(3, 2));
+            Ok(())
+        }
+
+        #[test]
+        fn check_impl_parsable() -> AoCResult<()> {
+            let grid = Grid::<Tile>::parse(&get_lines("#>\n>#"))?;
+            assert_eq!(grid.get_tile(&Point2D::new(1, 0)), Some(&Tile::Slope(Direction::East)));
+            assert_eq!(grid.get_tile(&Point2D::new(1, 1)), Some(&Tile::Wall));
+            let Err(error) = Tile::parse('.') else {
+                panic!("Parsing should fail")
+            };
+            assert_eq!(error, AoCError::BadInputFormat(
+                "Parsing Tile failed, expected one of '#', '>'. Found '.'".to_string()));
+            assert_eq!(Tile::parse_token(">")?, Tile::Slope(Direction::East));
+            assert!(Tile::parse_token("##").is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn check_parse_tokens() -> AoCResult<()> {
+            let grid = Grid::<u32>::parse_tokens(&get_lines("10 2 300\n4  50 6"))?;
+            assert_eq!(grid.get_tile(&Point2D::new(2, 0)), Some(&300));
+            assert_eq!(grid.get_tile(&Point2D::new(1, 1)), Some(&50));
+            let Err(error) = Grid::<u32>::parse_tokens(&get_lines("1 2\n3 x")) else {
+                panic!("Parsing should fail")
+            };
+            assert_eq!(error.get_context().and_then(|context| context.line), Some(1));
+            assert!(Grid::<u32>::parse_tokens(&get_lines("1 2\n3")).is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn check_parse_lines() -> AoCResult<()> {
+            let moves = Move::parse_lines(&get_lines("R 4\nL 12"))?;
+            assert_eq!(moves.iter().map(|m| m.1).sum::<usize>(), 16);
+            assert_eq!(moves[1].0, Direction::West);
+            let Err(error) = Move::parse_lines(&get_lines("R 4\nU 1")) else {
+                panic!("Parsing should fail")
+            };
+            assert_eq!(error.get_context().and_then(|context| context.line), Some(1));
+            Ok(())
+        }
+    }
 }
 
 pub mod graph;
+pub mod hex;
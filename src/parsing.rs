@@ -0,0 +1,390 @@
+use std::error::Error;
+use std::str::FromStr;
+use crate::errors::{AoCError, AoCResult, ResultExt};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Part {
+    Literal(String),
+    Field(String),
+}
+
+/// A line pattern of literal text and named '{field}' placeholders, e.g.
+/// `"{name} can fly {speed} km/s for {dur} seconds"`. Literal braces are written as '{{' and '}}'.
+/// A field matches everything up to the first occurrence of the literal following it, the last
+/// field matches the rest of the line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Pattern {
+    pattern: String,
+    parts: Vec<Part>,
+}
+
+/// The field values of a line matching a 'Pattern'.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fields<'a> {
+    line: &'a str,
+    /// Name, value and byte offset in the line of every field.
+    values: Vec<(String, &'a str, usize)>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> AoCResult<Self> {
+        let mut parts = vec![];
+        let mut literal = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' | '}' if chars.peek() == Some(&c) => {
+                    chars.next();
+                    literal.push(c);
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c.is_alphanumeric() || c == '_' => name.push(c),
+                            _ => return Err(AoCError::BadInputFormat(format!(
+                                "Pattern '{}' contains an invalid field name after '{{{}'.",
+                                pattern, name))),
+                        }
+                    }
+                    if name.is_empty() || parts.contains(&Part::Field(name.clone())) {
+                        return Err(AoCError::BadInputFormat(format!(
+                            "Pattern '{}' contains an empty or duplicate field name '{}'.",
+                            pattern, name)))
+                    }
+                    if literal.is_empty() && matches!(parts.last(), Some(Part::Field(_))) {
+                        return Err(AoCError::BadInputFormat(format!(
+                            "Pattern '{}' is ambiguous, field '{}' directly follows another field.",
+                            pattern, name)))
+                    }
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(literal));
+                        literal = String::new();
+                    }
+                    parts.push(Part::Field(name));
+                }
+                '}' => return Err(AoCError::BadInputFormat(format!(
+                    "Pattern '{}' contains an unmatched '}}'.", pattern))),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        Ok(Self { pattern: pattern.to_string(), parts })
+    }
+
+    /// Matches the line against the pattern. Errors point to the column where the line deviates.
+    pub fn parse<'a>(&self, line: &'a str) -> AoCResult<Fields<'a>> {
+        let mut position = 0;
+        let mut values = vec![];
+        for (index, part) in self.parts.iter().enumerate() {
+            match part {
+                Part::Literal(literal) => {
+                    if !line[position..].starts_with(literal.as_str()) {
+                        return Err(self.get_error(line, position,
+                                                  format!("expected '{}'", literal)))
+                    }
+                    position += literal.len();
+                }
+                Part::Field(name) => {
+                    let end = match self.parts.get(index+1) {
+                        Some(Part::Literal(next)) => line[position..].find(next.as_str())
+                            .map(|offset| position+offset)
+                            .ok_or_else(|| self.get_error(line, position, format!(
+                                "expected '{}' after field '{}'", next, name)))?,
+                        _ => line.len(),
+                    };
+                    if end == position {
+                        return Err(self.get_error(line, position,
+                                                  format!("field '{}' is empty", name)))
+                    }
+                    values.push((name.clone(), &line[position..end], position));
+                    position = end;
+                }
+            }
+        }
+        if position != line.len() {
+            return Err(self.get_error(line, position, "unexpected text at the end".to_string()))
+        }
+        Ok(Fields { line, values })
+    }
+
+    /// Parses every line using 'convert' on its fields. Errors contain the line they occurred in.
+    pub fn parse_lines<T, F>(&self, input: &[String], convert: F) -> AoCResult<Vec<T>>
+    where F: Fn(&Fields) -> AoCResult<T>
+    {
+        input.iter()
+            .enumerate()
+            .map(|(index, line)| self.parse(line)
+                .and_then(|fields| convert(&fields))
+                .at_line(index, line))
+            .collect()
+    }
+
+    fn get_error(&self, line: &str, position: usize, reason: String) -> AoCError<String> {
+        AoCError::BadInputFormat(format!("Line does not match '{}', {}.", self.pattern, reason))
+            .with_context(|context| {
+                context.column = Some(line[..position].chars().count());
+                context.text = Some(line.to_string());
+            })
+    }
+}
+
+impl<'a> Fields<'a> {
+    /// Returns the text matched by the given field.
+    pub fn get_str(&self, name: &str) -> AoCResult<&'a str> {
+        self.get_value(name).map(|(value, _)| value)
+    }
+
+    /// Parses the text matched by the given field.
+    pub fn get<T: FromStr>(&self, name: &str) -> AoCResult<T>
+    where T::Err: Error + Send + Sync + 'static
+    {
+        let (value, position) = self.get_value(name)?;
+        value.parse::<T>().map_err(|e| AoCError::BadInputFormat(format!(
+            "Parsing field '{}' failed, found '{}'.", name, value))
+            .with_context(|context| {
+                context.column = Some(self.line[..position].chars().count());
+                context.text = Some(self.line.to_string());
+            })
+            .with_source(e))
+    }
+
+    fn get_value(&self, name: &str) -> AoCResult<(&'a str, usize)> {
+        self.values.iter()
+            .find(|(field, _, _)| field == name)
+            .map(|(_, value, position)| (*value, *position))
+            .ok_or_else(|| AoCError::BadInputFormat(format!(
+                "The pattern has no field '{}'.", name)))
+    }
+}
+
+/// Splits the input on blank lines. Consecutive blank lines count as a single separator.
+pub fn split_on_blank_lines(input: &[String]) -> Vec<&[String]> {
+    get_sections(input).into_iter()
+        .map(|(_, section)| section)
+        .collect()
+}
+
+/// Splits the input on blank lines into exactly one section per name, e.g.
+/// `let [workflows, parts] = split_sections(input, ["workflows", "parts"])?;`. The names are used
+/// in the error message.
+pub fn split_sections<'a, const N: usize>(input: &'a [String], names: [&str; N])
+    -> AoCResult<[&'a [String]; N]>
+{
+    let sections = get_sections(input);
+    let error = || AoCError::BadInputFormat(format!(
+        "Expected {} sections separated by blank lines ({}), found {}.", N, names.join(", "),
+        sections.len()));
+    if let Some((start, _)) = sections.get(N) {
+        return Err(error().with_line(*start, &input[*start]))
+    }
+    let sections = sections.iter()
+        .map(|(_, section)| *section)
+        .collect::<Vec<_>>();
+    sections.try_into().map_err(|_| error())
+}
+
+/// Splits the input on blank lines into sections starting with a header line, like
+/// "seed-to-soil map:". Returns the header (without a trailing ':') and the lines following it.
+pub fn split_headed_sections(input: &[String]) -> Vec<(&str, &[String])> {
+    get_sections(input).into_iter()
+        .map(|(_, section)| (section[0].trim_end().trim_end_matches(':'), &section[1..]))
+        .collect()
+}
+
+/// Returns the index of the first line and the lines of each non-empty section.
+fn get_sections(input: &[String]) -> Vec<(usize, &[String])> {
+    let mut sections = vec![];
+    let mut start = 0;
+    for (index, line) in input.iter().enumerate() {
+        if line.trim().is_empty() {
+            if start < index {
+                sections.push((start, &input[start..index]));
+            }
+            start = index+1;
+        }
+    }
+    if start < input.len() {
+        sections.push((start, &input[start..]));
+    }
+    sections
+}
+
+/// Returns all integers of the line in order. A '-' directly in front of a number is its sign,
+/// unless it follows a digit ('10-20' is a range of two positive numbers).
+pub fn get_integers<T: FromStr>(line: &str) -> AoCResult<Vec<T>>
+where T::Err: Error + Send + Sync + 'static
+{
+    let mut integers = vec![];
+    let mut start = None;
+    let mut previous = None;
+    let mut chars = line.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let next_is_digit = chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+        if start.is_none() && (c.is_ascii_digit() ||
+            (c == '-' && next_is_digit && !previous.is_some_and(|p: char| p.is_ascii_digit())))
+        {
+            start = Some(index);
+        }
+        if let Some(first) = start {
+            if !next_is_digit {
+                let end = index+c.len_utf8();
+                integers.push(line[first..end].parse::<T>()
+                    .map_err(|e| AoCError::BadInputFormat(format!(
+                        "Parsing integer '{}' failed.", &line[first..end]))
+                        .with_context(|context| {
+                            context.column = Some(line[..first].chars().count());
+                            context.text = Some(line.to_string());
+                        })
+                        .with_source(e))?);
+                start = None;
+            }
+        }
+        previous = Some(c);
+    }
+    Ok(integers)
+}
+
+/// Returns the integers of the line, which has to contain exactly 'N', e.g.
+/// `let [x, y, z] = get_integer_array::<i64, 3>(line)?;`.
+pub fn get_integer_array<T: FromStr, const N: usize>(line: &str) -> AoCResult<[T; N]>
+where T::Err: Error + Send + Sync + 'static
+{
+    let integers = get_integers(line)?;
+    let count = integers.len();
+    integers.try_into().map_err(|_| AoCError::BadInputFormat(format!(
+        "Expected {} integers, found {}.", N, count))
+        .with_context(|context| context.text = Some(line.to_string())))
+}
+
+/// Returns the integers of every line of the input. Errors contain the line they occurred in.
+pub fn get_integers_per_line<T: FromStr>(input: &[String]) -> AoCResult<Vec<Vec<T>>>
+where T::Err: Error + Send + Sync + 'static
+{
+    input.iter()
+        .enumerate()
+        .map(|(index, line)| get_integers(line).at_line(index, line))
+        .collect()
+}
+
+/// Returns the integers of every line of the input, each line has to contain exactly 'N'.
+pub fn get_integer_arrays<T: FromStr, const N: usize>(input: &[String]) -> AoCResult<Vec<[T; N]>>
+where T::Err: Error + Send + Sync + 'static
+{
+    input.iter()
+        .enumerate()
+        .map(|(index, line)| get_integer_array(line).at_line(index, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::ParseIntError;
+    use super::*;
+
+    #[test]
+    fn check_parse() -> AoCResult<()> {
+        let pattern = Pattern::new("{name} can fly {speed} km/s for {dur} seconds")?;
+        let fields = pattern.parse("Comet can fly 14 km/s for 10 seconds")?;
+        assert_eq!(fields.get_str("name")?, "Comet");
+        assert_eq!(fields.get::<u32>("speed")?, 14);
+        assert_eq!(fields.get::<u8>("dur")?, 10);
+        assert!(fields.get_str("rest").is_err());
+
+        let pattern = Pattern::new("{{{x}}}: {rest}")?;
+        let fields = pattern.parse("{-3}: a: b")?;
+        assert_eq!(fields.get::<i32>("x")?, -3);
+        assert_eq!(fields.get_str("rest")?, "a: b");
+        Ok(())
+    }
+
+    #[test]
+    fn check_invalid_patterns() {
+        for pattern in ["{a}{b}", "{a} {a}", "{}", "{a", "a}", "{a b}"] {
+            assert!(Pattern::new(pattern).is_err(), "'{}' should be rejected", pattern);
+        }
+    }
+
+    #[test]
+    fn check_errors() -> AoCResult<()> {
+        let pattern = Pattern::new("{name} can fly {speed} km/s")?;
+        let error = pattern.parse("Comet can fly 14 mph").expect_err("'mph' does not match");
+        let context = error.get_context().expect("Errors have a position");
+        assert_eq!(context.column, Some(14));
+        assert!(pattern.parse("Comet can fly 14 km/s!").is_err());
+        assert!(pattern.parse("Comet can fly  km/s").is_err());
+
+        let input = vec!["Comet can fly 14 km/s".to_string(), "Dancer can fly x km/s".to_string()];
+        let error = pattern.parse_lines(&input, |fields| fields.get::<u32>("speed"))
+            .expect_err("'x' is no number");
+        assert!(matches!(error.get_inner(), AoCError::BadInputFormat(_)));
+        let context = error.get_context().expect("Errors have a position");
+        assert_eq!((context.line, context.column), (Some(1), Some(15)));
+        let source = error.source().expect("The parse error is kept as source");
+        assert!(source.downcast_ref::<ParseIntError>().is_some());
+        assert_eq!(source.to_string(), "invalid digit found in string");
+        Ok(())
+    }
+
+    fn get_lines(str: &str) -> Vec<String> {
+        str.lines().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn check_split_sections() -> AoCResult<()> {
+        let input = get_lines("\npx{a<2006:qkq,rfg}\n\n\n{x=787,m=2655}\n{x=1679,m=44}\n");
+        assert_eq!(split_on_blank_lines(&input).len(), 2);
+        let [workflows, parts] = split_sections(&input, ["workflows", "parts"])?;
+        assert_eq!(workflows, &input[1..2]);
+        assert_eq!(parts, &input[4..6]);
+
+        let error = split_sections(&input, ["workflows"]).expect_err("There are two sections");
+        assert_eq!(error.get_context().and_then(|context| context.line), Some(4));
+        let error = split_sections(&input, ["seeds", "maps", "locations"])
+            .expect_err("There are only two sections");
+        assert_eq!(error, AoCError::BadInputFormat("Expected 3 sections separated by blank lines \
+            (seeds, maps, locations), found 2.".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn check_split_headed_sections() {
+        let input = get_lines("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48");
+        let sections = split_headed_sections(&input);
+        assert_eq!(sections[0], ("seeds: 79 14", &input[1..1]));
+        assert_eq!(sections[1], ("seed-to-soil map", &input[3..5]));
+    }
+
+    #[test]
+    fn check_get_integers() -> AoCResult<()> {
+        let line = "p=<-317,1413,1507>, v=<19,-102,-68>, a=<1,-3,-3>";
+        assert_eq!(get_integers::<i32>(line)?, vec![-317, 1413, 1507, 19, -102, -68, 1, -3, -3]);
+        assert_eq!(get_integers::<u64>("5-8 and 12-13")?, vec![5, 8, 12, 13]);
+        assert!(get_integers::<i8>("a-b -")?.is_empty());
+        let line = "Disc #1 has 5 positions; at time=0, it is at position 4.";
+        let [index, states, _, start] = get_integer_array::<usize, 4>(line)?;
+        assert_eq!((index, states, start), (1, 5, 4));
+
+        let error = get_integers::<u8>("x=3, y=-4").expect_err("-4 is no u8");
+        assert_eq!(error.get_context().and_then(|context| context.column), Some(7));
+        assert!(get_integers::<u8>("256").is_err());
+        assert!(get_integer_array::<i32, 2>("1, 2, 3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_get_integers_per_line() -> AoCResult<()> {
+        let input = get_lines("19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2");
+        let hailstones = get_integer_arrays::<i64, 6>(&input)?;
+        assert_eq!(hailstones[1], [18, 19, 22, -1, -1, -2]);
+        assert_eq!(get_integers_per_line::<i64>(&input)?[0], vec![19, 13, 30, -2, 1, -2]);
+
+        let input = get_lines("1 2\n3");
+        let error = get_integer_arrays::<u8, 2>(&input).expect_err("The second line has one");
+        assert_eq!(error.get_context().and_then(|context| context.line), Some(1));
+        Ok(())
+    }
+}
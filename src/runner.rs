@@ -0,0 +1,603 @@
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Write};
+use std::panic::catch_unwind;
+use std::path::Path;
+use std::sync::{mpsc, OnceLock, PoisonError, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use crate::errors::{AoCError, AoCResult};
+use crate::input::{get_input_for_profile, get_input_from_file};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(String),
+    Failed(AoCError<String>),
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Outcome::Solved(_))
+    }
+}
+
+/// Where the runner takes the inputs from.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum InputSource<'a> {
+    /// The input cache of the default account.
+    Cache,
+    /// The input cache of the given profile.
+    Profile(&'a str),
+    /// The given file, used as input for all solutions.
+    File(&'a Path),
+}
+
+pub struct Report {
+    pub solution: Solution,
+    pub outcome: Outcome,
+    /// Time spent solving this part.
+    pub duration: Duration,
+    /// Time spent loading the input of the day (shared by both parts).
+    pub input_duration: Duration,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.outcome {
+            Outcome::Solved(answer) => write!(f, "{}: {}", self.solution, answer),
+            Outcome::Failed(error) =>
+                write!(f, "{} failed: {}", self.solution, error.get_report()),
+            Outcome::Panicked(message) => write!(f, "{} panicked: {}", self.solution, message),
+        }
+    }
+}
+
+/// Runs all given solutions, loading the input once per day. Failing inputs, errors and panics
+/// are reported for the affected parts and do not stop the remaining solutions.
+/// Each report is passed to 'on_report' as soon as it is available.
+pub fn run<F: FnMut(&Report)>(solutions: &[Solution], source: InputSource, mut on_report: F)
+    -> Vec<Report>
+{
+    let mut reports = Vec::with_capacity(solutions.len());
+    for day in solutions.chunk_by(|a, b| a.year == b.year && a.day == b.day) {
+        let input = load_input(&day[0], source);
+        for solution in day {
+            let report = run_task(solution, &input);
+            on_report(&report);
+            reports.push(report);
+        }
+    }
+    reports
+}
+
+/// Same as 'run(..)', but distributes the parts onto 'jobs' threads. Exclusive solutions (which use
+/// all cores themselves) only run while no other solution is running.
+/// The reports are still passed to 'on_report' in the order of 'solutions'. Note that timings are
+/// less accurate, since the solutions compete for the cores.
+pub fn run_parallel<F: FnMut(&Report)>(solutions: &[Solution], source: InputSource,
+                                       jobs: usize, mut on_report: F) -> Vec<Report>
+{
+    let mut day_indices = Vec::with_capacity(solutions.len());
+    let mut day_count = 0;
+    for day in solutions.chunk_by(|a, b| a.year == b.year && a.day == b.day) {
+        day_indices.extend(std::iter::repeat_n(day_count, day.len()));
+        day_count += 1;
+    }
+    let inputs = (0..day_count)
+        .map(|_| OnceLock::new())
+        .collect::<Vec<_>>();
+    let next_task = AtomicUsize::new(0);
+    let exclusive = RwLock::new(());
+    let (sender, receiver) = mpsc::channel();
+
+    let mut reports = Vec::with_capacity(solutions.len());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, solutions.len().max(1)) {
+            let sender = sender.clone();
+            let (inputs, day_indices, next_task, exclusive) =
+                (&inputs, &day_indices, &next_task, &exclusive);
+            scope.spawn(move || {
+                loop {
+                    let index = next_task.fetch_add(1, Ordering::SeqCst);
+                    let Some(solution) = solutions.get(index) else {
+                        break
+                    };
+                    // exclusive solutions wait for all running ones and block new ones meanwhile
+                    let _guard = if solution.exclusive {
+                        Err(exclusive.write().unwrap_or_else(PoisonError::into_inner))
+                    } else {
+                        Ok(exclusive.read().unwrap_or_else(PoisonError::into_inner))
+                    };
+                    let input = inputs[day_indices[index]]
+                        .get_or_init(|| load_input(solution, source));
+                    if sender.send((index, run_task(solution, input))).is_err() {
+                        break
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        for (index, report) in receiver {
+            pending.insert(index, report);
+            while let Some(report) = pending.remove(&reports.len()) {
+                on_report(&report);
+                reports.push(report);
+            }
+        }
+    });
+    reports
+}
+
+fn load_input(solution: &Solution, source: InputSource) -> (AoCResult<Vec<String>>, Duration) {
+    let start = Instant::now();
+    let input = match source {
+        InputSource::Cache => get_input_for_profile(solution.year, solution.day, None),
+        InputSource::Profile(name) =>
+            get_input_for_profile(solution.year, solution.day, Some(name)),
+        InputSource::File(path) => get_input_from_file(path),
+    };
+    (input, start.elapsed())
+}
+
+fn run_task(solution: &Solution, input: &(AoCResult<Vec<String>>, Duration)) -> Report {
+    let (input, input_duration) = input;
+    let start = Instant::now();
+    let outcome = match input {
+        Ok(input) => run_solution(solution, input),
+        Err(e) => Outcome::Failed(e.clone()),
+    };
+    let duration = start.elapsed();
+    Report { solution: *solution, outcome, duration, input_duration: *input_duration }
+}
+
+/// Runs a single solution, catching errors as well as panics.
+pub fn run_solution(solution: &Solution, input: &Vec<String>) -> Outcome {
+    match catch_unwind(|| solution.run(input)) {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(e)) => Outcome::Failed(complete_context(e, solution, input)),
+        Err(payload) => Outcome::Panicked(panic_message(payload)),
+    }
+}
+
+/// Adds the day and the offending input line to errors pointing into the input.
+fn complete_context(error: AoCError<String>, solution: &Solution, input: &[String])
+    -> AoCError<String>
+{
+    let Some(context) = error.get_context() else {
+        return error
+    };
+    let text = match (context.line, &context.text) {
+        (Some(line), None) => input.get(line).cloned(),
+        _ => None,
+    };
+    let add_day = context.year.is_none();
+    error.with_context(|context| {
+        if add_day {
+            context.year = Some(solution.year);
+            context.day = Some(solution.day);
+        }
+        if text.is_some() {
+            context.text = text;
+        }
+    })
+}
+
+/// Table of all reports sorted by the time spent solving, slowest first. Solutions taking longer
+/// than 'budget' are flagged.
+pub struct Summary<'a> {
+    reports: &'a [Report],
+    budget: Duration,
+}
+
+impl<'a> Summary<'a> {
+    pub fn new(reports: &'a [Report], budget: Duration) -> Self {
+        Self { reports, budget }
+    }
+
+    pub fn get_over_budget(&self) -> Vec<&'a Report> {
+        self.reports.iter()
+            .filter(|report| report.duration > self.budget)
+            .collect()
+    }
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut sorted = self.reports.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|report| Reverse(report.duration));
+
+        writeln!(f, "{:<14} {:>12} {:>12}  status", "solution", "input", "solve")?;
+        for report in sorted {
+            let status = match report.outcome {
+                Outcome::Solved(_) if report.duration > self.budget => "over budget",
+                Outcome::Solved(_) => "ok",
+                Outcome::Failed(_) => "failed",
+                Outcome::Panicked(_) => "panicked",
+            };
+            writeln!(f, "{:<14} {:>12} {:>12}  {}", report.solution.to_string(),
+                     format_duration(report.input_duration), format_duration(report.duration),
+                     status)?;
+        }
+        let total = self.reports.iter()
+            .map(|report| report.duration)
+            .sum::<Duration>();
+        writeln!(f, "{:<14} {:>12} {:>12}", "total", "", format_duration(total))?;
+        write!(f, "{} of {} solutions over the budget of {}", self.get_over_budget().len(),
+               self.reports.len(), format_duration(self.budget))
+    }
+}
+
+/// Answers of the same solutions on the inputs of several profiles, one report list per profile
+/// (all in the same order). Solutions whose outcomes differ between the profiles are marked.
+pub struct ProfileComparison<'a> {
+    profiles: &'a [String],
+    reports: &'a [Vec<Report>],
+}
+
+impl<'a> ProfileComparison<'a> {
+    pub fn new(profiles: &'a [String], reports: &'a [Vec<Report>]) -> Self {
+        assert_eq!(profiles.len(), reports.len(), "One report list per profile is required");
+        Self { profiles, reports }
+    }
+
+    /// Returns the solutions whose outcomes are not the same for all profiles.
+    pub fn get_differing(&self) -> Vec<Solution> {
+        (0..self.get_solution_count())
+            .filter(|index| self.is_differing(*index))
+            .map(|index| self.reports[0][index].solution)
+            .collect()
+    }
+
+    fn get_solution_count(&self) -> usize {
+        self.reports.iter()
+            .map(|reports| reports.len())
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn is_differing(&self, index: usize) -> bool {
+        self.reports.iter()
+            .map(|reports| &reports[index].outcome)
+            .any(|outcome| *outcome != self.reports[0][index].outcome)
+    }
+}
+
+impl Display for ProfileComparison<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cell = |outcome: &Outcome| match outcome {
+            Outcome::Solved(answer) if answer.contains('\n') =>
+                format!("<{} lines>", answer.lines().count()),
+            Outcome::Solved(answer) => answer.clone(),
+            Outcome::Failed(_) => "failed".to_string(),
+            Outcome::Panicked(_) => "panicked".to_string(),
+        };
+        let rows = (0..self.get_solution_count())
+            .map(|index| self.reports.iter()
+                .map(|reports| cell(&reports[index].outcome))
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let widths = self.profiles.iter().enumerate()
+            .map(|(column, profile)| rows.iter()
+                .map(|row| row[column].len())
+                .fold(profile.len(), usize::max))
+            .collect::<Vec<_>>();
+
+        write!(f, "{:<14}", "solution")?;
+        for (profile, width) in self.profiles.iter().zip(&widths) {
+            write!(f, " {:>width$}", profile, width = width)?;
+        }
+        writeln!(f)?;
+        for (index, row) in rows.iter().enumerate() {
+            write!(f, "{:<14}", self.reports[0][index].solution.to_string())?;
+            for (answer, width) in row.iter().zip(&widths) {
+                write!(f, " {:>width$}", answer, width = width)?;
+            }
+            if self.is_differing(index) {
+                write!(f, "  differs")?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "{} of {} solutions differ between the profiles", self.get_differing().len(),
+               rows.len())
+    }
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<&'a str>,
+    error_variant: Option<&'static str>,
+    error: Option<String>,
+    duration_ms: f64,
+    input_duration_ms: f64,
+}
+
+/// Serializes the reports into a JSON array, one object per solution.
+pub fn to_json(reports: &[Report]) -> String {
+    let reports = reports.iter()
+        .map(|report| {
+            let (status, answer, error_variant, error) = match &report.outcome {
+                Outcome::Solved(answer) => ("solved", Some(answer.as_str()), None, None),
+                Outcome::Failed(e) =>
+                    ("failed", None, Some(e.get_variant_name()), Some(e.get_report())),
+                Outcome::Panicked(message) =>
+                    ("panicked", None, Some("Panic"), Some(message.clone())),
+            };
+            JsonReport {
+                year: report.solution.year,
+                day: report.solution.day,
+                part: report.solution.part,
+                status,
+                answer,
+                error_variant,
+                error,
+                duration_ms: report.duration.as_secs_f64() * 1e3,
+                input_duration_ms: report.input_duration.as_secs_f64() * 1e3,
+            }
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&reports).expect("Serializing reports cannot fail")
+}
+
+/// Serializes the reports as JUnit XML. Each year is a test suite, each part a test case. Errors
+/// are reported as failures, panics as errors.
+pub fn to_junit(reports: &[Report]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(xml, "<testsuites {}>", junit_counts(reports));
+    for year in reports.chunk_by(|a, b| a.solution.year == b.solution.year) {
+        let _ = writeln!(xml, "  <testsuite name=\"year_{}\" {}>", year[0].solution.year,
+                         junit_counts(year));
+        for report in year {
+            let _ = writeln!(xml, "    <testcase classname=\"year_{}.day_{:02}\" name=\"part_{}\" \
+                time=\"{:.6}\">", report.solution.year, report.solution.day, report.solution.part,
+                             report.duration.as_secs_f64());
+            let _ = match &report.outcome {
+                Outcome::Solved(answer) =>
+                    writeln!(xml, "      <system-out>{}</system-out>", escape_xml(answer)),
+                Outcome::Failed(e) =>
+                    writeln!(xml, "      <failure type=\"{}\" message=\"{}\"/>",
+                             e.get_variant_name(), escape_xml(&e.get_report())),
+                Outcome::Panicked(message) =>
+                    writeln!(xml, "      <error type=\"Panic\" message=\"{}\"/>",
+                             escape_xml(message)),
+            };
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn junit_counts(reports: &[Report]) -> String {
+    let failures = reports.iter()
+        .filter(|report| matches!(report.outcome, Outcome::Failed(_)))
+        .count();
+    let errors = reports.iter()
+        .filter(|report| matches!(report.outcome, Outcome::Panicked(_)))
+        .count();
+    let time = reports.iter()
+        .map(|report| report.duration)
+        .sum::<Duration>();
+    format!("tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\"", reports.len(), failures,
+            errors, time.as_secs_f64())
+}
+
+fn escape_xml(str: &str) -> String {
+    str.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\n', "&#10;")
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn solve(_: &Vec<String>) -> AoCResult<String> {
+        Ok("42".to_string())
+    }
+
+    fn fail(_: &Vec<String>) -> AoCResult<String> {
+        Err(AoCError::BadInputFormat("bad line".to_string()))
+    }
+
+    fn panic(_: &Vec<String>) -> AoCResult<String> {
+        panic!("index out of bounds")
+    }
+
+    #[test]
+    fn check_run_solution() {
+        let input = vec![];
+        assert_eq!(run_solution(&Solution::new(2015, 1, 1, solve), &input),
+                   Outcome::Solved("42".to_string()));
+        assert_eq!(run_solution(&Solution::new(2015, 1, 2, fail), &input),
+                   Outcome::Failed(AoCError::BadInputFormat("bad line".to_string())));
+        assert_eq!(run_solution(&Solution::new(2015, 2, 1, panic), &input),
+                   Outcome::Panicked("index out of bounds".to_string()));
+    }
+
+    fn fail_in_line(_: &Vec<String>) -> AoCResult<String> {
+        Err(AoCError::BadInputFormat("Unknown register 'x'.".to_string())
+            .with_context(|context| context.line = Some(1))
+            .with_column(4)
+            .with_cause("not one of 'a', 'b'"))
+    }
+
+    #[test]
+    fn check_error_context() {
+        let input = vec!["inc a".to_string(), "jio x, +19".to_string()];
+        let outcome = run_solution(&Solution::new(2015, 23, 1, fail_in_line), &input);
+        let Outcome::Failed(error) = outcome else {
+            panic!("Solution should fail")
+        };
+        assert_eq!(error.get_inner(),
+                   &AoCError::BadInputFormat("Unknown register 'x'.".to_string()));
+        assert_eq!(error.get_variant_name(), "BadInputFormat");
+        assert_eq!(error.to_string(), "The input has unexpected input:\nUnknown register 'x'.\n \
+            --> 2015 day 23, line 2, column 5\n  |\n2 | jio x, +19\n  |     ^\n\
+            caused by: not one of 'a', 'b'");
+    }
+
+    #[test]
+    fn check_run_continues_after_failure() {
+        let solutions = [
+            Solution::new(2015, 1, 1, panic),
+            Solution::new(2015, 1, 2, solve),
+        ];
+        let path = std::env::temp_dir().join("aoc_runner_check_run_continues_after_failure.txt");
+        std::fs::write(&path, "input").expect("Writing temporary input failed");
+        let mut count = 0;
+        let reports = run(&solutions, InputSource::File(&path), |_| count += 1);
+        assert_eq!(count, 2);
+        assert!(matches!(reports[0].outcome, Outcome::Panicked(_)));
+        assert_eq!(reports[1].outcome, Outcome::Solved("42".to_string()));
+        assert_eq!(reports[1].to_string(), "y2015-d01_p2: 42");
+    }
+
+    static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+    fn shared(_: &Vec<String>) -> AoCResult<String> {
+        RUNNING.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(20));
+        RUNNING.fetch_sub(1, Ordering::SeqCst);
+        Ok("shared".to_string())
+    }
+
+    fn exclusive(_: &Vec<String>) -> AoCResult<String> {
+        Ok(RUNNING.load(Ordering::SeqCst).to_string())
+    }
+
+    #[test]
+    fn check_run_parallel() {
+        let mut solutions = (1..=8)
+            .flat_map(|day| [
+                Solution::new(2015, day, 1, shared),
+                Solution::new(2015, day, 2, shared),
+            ])
+            .collect::<Vec<_>>();
+        solutions[5] = Solution::new(2015, 3, 2, exclusive).with_exclusive(true);
+        solutions[11] = Solution::new(2015, 6, 2, exclusive).with_exclusive(true);
+        let path = std::env::temp_dir().join("aoc_runner_check_run_parallel.txt");
+        std::fs::write(&path, "input").expect("Writing temporary input failed");
+        let mut order = vec![];
+        let reports = run_parallel(&solutions, InputSource::File(&path), 4,
+                                   |report| order.push(report.solution.to_string()));
+        let expected = solutions.iter()
+            .map(|solution| solution.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(order, expected);
+        assert_eq!(reports[5].outcome, Outcome::Solved("0".to_string()));
+        assert_eq!(reports[11].outcome, Outcome::Solved("0".to_string()));
+        assert_eq!(reports[12].outcome, Outcome::Solved("shared".to_string()));
+    }
+
+    #[test]
+    fn check_summary() {
+        let report = |day, duration| Report {
+            solution: Solution::new(2015, day, 1, solve),
+            outcome: Outcome::Solved("42".to_string()),
+            duration: Duration::from_millis(duration),
+            input_duration: Duration::ZERO,
+        };
+        let reports = [report(1, 20), report(2, 1500), report(3, 300)];
+        let summary = Summary::new(&reports, Duration::from_secs(1));
+        assert_eq!(summary.get_over_budget().len(), 1);
+        let table = summary.to_string();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[1].starts_with("y2015-d02_p1") && lines[1].ends_with("over budget"));
+        assert!(lines[2].starts_with("y2015-d03_p1") && lines[2].ends_with("ok"));
+        assert!(lines[3].starts_with("y2015-d01_p1"));
+        assert!(lines[4].contains("1.82s"));
+    }
+
+    #[test]
+    fn check_profile_comparison() {
+        let report = |part, answer: &str| Report {
+            solution: Solution::new(2015, 1, part, solve),
+            outcome: Outcome::Solved(answer.to_string()),
+            duration: Duration::ZERO,
+            input_duration: Duration::ZERO,
+        };
+        let profiles = ["alice".to_string(), "bob".to_string()];
+        let reports = [
+            vec![report(1, "138"), report(2, "#.\n.#")],
+            vec![report(1, "232"), report(2, "#.\n.#")],
+        ];
+        let comparison = ProfileComparison::new(&profiles, &reports);
+        let differing = comparison.get_differing();
+        assert_eq!(differing.len(), 1);
+        assert_eq!(differing[0].to_string(), "y2015-d01_p1");
+        let table = comparison.to_string();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "solution           alice       bob");
+        assert_eq!(lines[1], "y2015-d01_p1         138       232  differs");
+        assert_eq!(lines[2], "y2015-d01_p2   <2 lines> <2 lines>");
+        assert_eq!(lines[3], "1 of 2 solutions differ between the profiles");
+    }
+
+    fn get_example_reports() -> Vec<Report> {
+        let report = |year, part, outcome| Report {
+            solution: Solution::new(year, 1, part, solve),
+            outcome,
+            duration: Duration::from_millis(2),
+            input_duration: Duration::ZERO,
+        };
+        vec![
+            report(2015, 1, Outcome::Solved("<42>".to_string())),
+            report(2015, 2, Outcome::Failed(AoCError::BadInputFormat("bad line".to_string()))),
+            report(2016, 1, Outcome::Panicked("not yet implemented".to_string())),
+        ]
+    }
+
+    #[test]
+    fn check_json() {
+        let json = to_json(&get_example_reports());
+        let value = serde_json::from_str::<serde_json::Value>(&json)
+            .expect("Output should be valid JSON");
+        assert_eq!(value[0]["answer"], "<42>");
+        assert_eq!(value[0]["duration_ms"], 2.0);
+        assert_eq!(value[1]["status"], "failed");
+        assert_eq!(value[1]["error_variant"], "BadInputFormat");
+        assert_eq!(value[2]["year"], 2016);
+        assert_eq!(value[2]["error_variant"], "Panic");
+    }
+
+    #[test]
+    fn check_junit() {
+        let xml = to_junit(&get_example_reports());
+        assert!(xml.contains("<testsuites tests=\"3\" failures=\"1\" errors=\"1\" \
+            time=\"0.006000\">"));
+        assert!(xml.contains("<testsuite name=\"year_2015\" tests=\"2\" failures=\"1\" \
+            errors=\"0\""));
+        assert!(xml.contains("<system-out>&lt;42&gt;</system-out>"));
+        assert!(xml.contains("<failure type=\"BadInputFormat\" message=\"The input has unexpected \
+            input:&#10;bad line\"/>"));
+        assert!(xml.contains("<error type=\"Panic\" message=\"not yet implemented\"/>"));
+    }
+}
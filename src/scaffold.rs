@@ -0,0 +1,187 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::errors::{AoCError, AoCResult};
+
+/// Creates 'year_YYYY/day_DD.rs' from the 'day_XX.txt' template of that year, declares the module
+/// in 'year_YYYY/mod.rs' and registers the day there. Benchmarks pick up registered days on their
+/// own. Returns the paths of all created or modified files.
+pub fn create_day(src_dir: &Path, year: u16, day: u8) -> AoCResult<Vec<PathBuf>> {
+    if day == 0 || day > 25 {
+        return Err(AoCError::BadInputFormat("Only days 1-25 supported.".to_string()))
+    }
+    let year_dir = src_dir.join(format!("year_{}", year));
+    let template_path = year_dir.join("day_XX.txt");
+    let mod_path = year_dir.join("mod.rs");
+    let day_path = year_dir.join(format!("day_{:02}.rs", day));
+    if day_path.exists() {
+        return Err(AoCError::BadInputFormat(format!("'{}' already exists.", day_path.display())))
+    }
+
+    let template = read(&template_path)?;
+    let mod_rs = read(&mod_path)?;
+    let mod_rs = register_day(&declare_module(&mod_rs, day)?, year, day)?;
+
+    write(&day_path, &fill_template(&template, year, day))?;
+    write(&mod_path, &mod_rs)?;
+    Ok(vec![day_path, mod_path])
+}
+
+/// Replaces the day placeholders of the template.
+pub fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template.replace("20XX", &year.to_string())
+        .replace("XX)?;   // TODO", &format!("{})?;", day))
+}
+
+/// Adds 'pub mod day_DD;' to the module list. Commented out declarations of that day are reused.
+pub fn declare_module(mod_rs: &str, day: u8) -> AoCResult<String> {
+    let declaration = format!("pub mod day_{:02};", day);
+    let mut lines = mod_rs.lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+
+    let mut in_comment = false;
+    let mut insert_after = None;
+    for index in 0..lines.len() {
+        let line = lines[index].trim().to_string();
+        let opens_comment = line.ends_with("/*");
+        let module = line.trim_end_matches("/*");
+        if in_comment && module == declaration && index > 0 && lines[index-1].ends_with("/*") {
+            // first declaration of a commented out block, move the block start behind it
+            let previous = &mut lines[index-1];
+            previous.truncate(previous.len()-2);
+            if lines.get(index+1).map(|next| next.trim()) == Some("*/") {
+                lines[index] = declaration;
+                lines.remove(index+1);
+            } else {
+                lines[index] = format!("{}/*", declaration);
+            }
+            return Ok(join_lines(lines, mod_rs))
+        }
+        if !in_comment && module == declaration {
+            return Err(AoCError::BadInputFormat(format!("Module day_{:02} already exists.", day)))
+        }
+        if !in_comment && line == format!("// {}", declaration) {
+            lines[index] = declaration;
+            return Ok(join_lines(lines, mod_rs))
+        }
+        if !in_comment && (module.starts_with("pub mod lib_") ||
+            (module.starts_with("pub mod day_") && *module < *declaration))
+        {
+            insert_after = Some(index);
+        }
+        if line.starts_with("/*") || opens_comment {
+            in_comment = true;
+        }
+        if line.ends_with("*/") {
+            in_comment = false;
+        }
+    }
+
+    let index = insert_after
+        .ok_or_else(|| AoCError::BadInputFormat("No module declarations found.".to_string()))?;
+    if lines[index].trim().starts_with("pub mod lib_") {
+        lines.insert(index+1, String::new());
+        lines.insert(index+2, declaration);
+    } else if lines[index].ends_with("/*") {
+        let previous = &mut lines[index];
+        previous.truncate(previous.len()-2);
+        lines.insert(index+1, format!("{}/*", declaration));
+    } else {
+        lines.insert(index+1, declaration);
+    }
+    Ok(join_lines(lines, mod_rs))
+}
+
+/// Adds the day to the 'register_days!' list of the year.
+pub fn register_day(mod_rs: &str, year: u16, day: u8) -> AoCResult<String> {
+    let mut lines = mod_rs.lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    let start = lines.iter()
+        .position(|line| line.trim() == format!("register_days!({},", year))
+        .ok_or_else(|| AoCError::BadInputFormat(
+            format!("No 'register_days!({}, ..)' found.", year)))?;
+
+    let mut index = start+1;
+    while index < lines.len() {
+        let line = lines[index].trim();
+        let Some((registered, _)) = line.split_once(" => ") else {
+            break
+        };
+        let registered = registered.parse::<u8>()
+            .map_err(|e| AoCError::BadInputFormat(format!(
+                "Parsing registered day '{}' failed: {}", registered, e)))?;
+        if registered == day {
+            return Err(AoCError::BadInputFormat(format!("Day {} is already registered.", day)))
+        }
+        if registered > day {
+            break
+        }
+        index += 1;
+    }
+    lines.insert(index, format!("        {} => day_{:02},", day, day));
+    Ok(join_lines(lines, mod_rs))
+}
+
+fn join_lines(lines: Vec<String>, original: &str) -> String {
+    let mut res = lines.join("\n");
+    if original.ends_with('\n') {
+        res.push('\n');
+    }
+    res
+}
+
+fn read(path: &Path) -> AoCResult<String> {
+    fs::read_to_string(path)
+        .map_err(|e| AoCError::IOError(format!("Reading '{}' failed: {}", path.display(), e)))
+}
+
+fn write(path: &Path, content: &str) -> AoCResult<()> {
+    fs::write(path, content)
+        .map_err(|e| AoCError::IOError(format!("Writing to '{}' failed: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_fill_template() {
+        let template = "let input = get_input(2024, XX)?;   // TODO\n";
+        assert_eq!(fill_template(template, 2024, 3), "let input = get_input(2024, 3)?;\n");
+    }
+
+    #[test]
+    fn check_declare_module() -> AoCResult<()> {
+        let block = "pub mod lib_2024;\n\npub mod day_01;\npub mod day_02;/*\npub mod day_03;\n\
+            pub mod day_04;\n*/\n";
+        assert_eq!(declare_module(block, 3)?, "pub mod lib_2024;\n\npub mod day_01;\n\
+            pub mod day_02;\npub mod day_03;/*\npub mod day_04;\n*/\n");
+        assert_eq!(declare_module("pub mod day_24;/*\npub mod day_25;\n*/\n", 25)?,
+                   "pub mod day_24;\npub mod day_25;\n");
+
+        let line_comments = "pub mod day_22;\n// pub mod day_23;\n// pub mod day_24;\n";
+        assert_eq!(declare_module(line_comments, 23)?,
+                   "pub mod day_22;\npub mod day_23;\n// pub mod day_24;\n");
+
+        let plain = "pub mod lib_2025;\n\npub mod day_01;\npub mod day_03;\n";
+        assert_eq!(declare_module(plain, 2)?,
+                   "pub mod lib_2025;\n\npub mod day_01;\npub mod day_02;\npub mod day_03;\n");
+        assert_eq!(declare_module("pub mod lib_2025;\n", 1)?,
+                   "pub mod lib_2025;\n\npub mod day_01;\n");
+        assert!(declare_module(plain, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_register_day() -> AoCResult<()> {
+        let mod_rs = "pub fn solutions() -> Vec<Solution> {\n    register_days!(2024,\n        \
+            1 => day_01,\n        2 => day_02,\n    )\n}\n";
+        assert_eq!(register_day(mod_rs, 2024, 3)?, "pub fn solutions() -> Vec<Solution> {\n    \
+            register_days!(2024,\n        1 => day_01,\n        2 => day_02,\n        \
+            3 => day_03,\n    )\n}\n");
+        assert!(register_day(mod_rs, 2024, 2).is_err());
+        assert!(register_day(mod_rs, 2023, 3).is_err());
+        Ok(())
+    }
+}
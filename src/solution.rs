@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+use crate::errors::AoCResult;
+use crate::{year_2015, year_2016, year_2017, year_2023, year_2024};
+
+pub type SolutionFn = fn(&Vec<String>) -> AoCResult<String>;
+
+/// A single part of a single day, runnable on the puzzle input of that day.
+#[derive(Copy, Clone)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Exclusive solutions use all cores on their own and should not run alongside others.
+    pub exclusive: bool,
+    function: SolutionFn,
+}
+
+impl Solution {
+    pub fn new(year: u16, day: u8, part: u8, function: SolutionFn) -> Self {
+        Self { year, day, part, exclusive: false, function }
+    }
+
+    pub fn with_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    pub fn run(&self, input: &Vec<String>) -> AoCResult<String> {
+        (self.function)(input)
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "y{}-d{:02}_p{}", self.year, self.day, self.part)
+    }
+}
+
+/// Returns the solutions of all years, ordered by year, day and part.
+pub fn get_all() -> Vec<Solution> {
+    let mut res = vec![];
+    res.append(&mut year_2015::solutions());
+    res.append(&mut year_2016::solutions());
+    res.append(&mut year_2017::solutions());
+    res.append(&mut year_2023::solutions());
+    res.append(&mut year_2024::solutions());
+    res
+}
+
+pub fn get_year(year: u16) -> Vec<Solution> {
+    get_all().into_iter()
+        .filter(|solution| solution.year == year)
+        .collect()
+}
+
+pub fn get_day(year: u16, day: u8) -> Vec<Solution> {
+    get_all().into_iter()
+        .filter(|solution| solution.year == year && solution.day == day)
+        .collect()
+}
+
+pub fn get(year: u16, day: u8, part: u8) -> Option<Solution> {
+    get_all().into_iter()
+        .find(|solution| solution.year == year && solution.day == day && solution.part == part)
+}
+
+/// Creates the list of solutions for one year. Each listed day module needs to provide a `part_1`
+/// and a `part_2` function. Days using all cores on their own are marked as `(exclusive)`.
+/// ```ignore
+/// register_days!(2024,
+///     1 => day_01,
+///     2 => day_02 (exclusive),
+/// )
+/// ```
+macro_rules! register_days {
+    (@exclusive) => { false };
+    (@exclusive exclusive) => { true };
+    ($year:expr, $($day:expr => $module:ident $(($mode:ident))?),* $(,)?) => {
+        vec![
+            $(
+                $crate::solution::Solution::new($year, $day, 1, |input| $module::part_1(input))
+                    .with_exclusive($crate::solution::register_days!(@exclusive $($mode)?)),
+                $crate::solution::Solution::new($year, $day, 2, |input| $module::part_2(input))
+                    .with_exclusive($crate::solution::register_days!(@exclusive $($mode)?)),
+            )*
+        ]
+    };
+}
+pub(crate) use register_days;
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn check_registry_unique() {
+        let all = get_all();
+        let keys = all.iter()
+            .map(|solution| (solution.year, solution.day, solution.part))
+            .collect::<HashSet<_>>();
+        assert_eq!(keys.len(), all.len());
+    }
+
+    #[test]
+    fn check_registry_ordered() {
+        let all = get_all();
+        assert!(all.windows(2).all(|w|
+            (w[0].year, w[0].day, w[0].part) < (w[1].year, w[1].day, w[1].part)));
+    }
+
+    #[test]
+    fn check_lookup() {
+        let solution = get(2016, 11, 2).expect("2016 day 11 part 2 is registered");
+        assert_eq!(solution.to_string(), "y2016-d11_p2");
+        assert_eq!(get_day(2015, 4).len(), 2);
+        assert_eq!(get_year(2015).len(), 50);
+        assert!(get(2016, 26, 1).is_none());
+    }
+}
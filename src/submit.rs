@@ -0,0 +1,351 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use reqwest::header::COOKIE;
+use serde::{Deserialize, Serialize};
+use crate::answers::{get_answers_dir, Answers};
+use crate::errors::{AoCError, AoCResult};
+use crate::input::InputConfig;
+
+/// Minimum time between two submissions of the same year.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Response of the server to a submitted answer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling whether it is too high or too low.
+    Incorrect,
+    /// An answer was submitted too recently, nothing was checked.
+    Wait,
+    /// The part is already solved or not unlocked yet, nothing was checked.
+    WrongLevel,
+}
+
+impl Response {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Response::TooHigh | Response::TooLow | Response::Incorrect)
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Response::Correct => "correct",
+            Response::TooHigh => "too high",
+            Response::TooLow => "too low",
+            Response::Incorrect => "wrong",
+            Response::Wait => "submitted too recently",
+            Response::WrongLevel => "for a part that is solved or locked",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+    /// Time of the submission in seconds since the unix epoch.
+    pub time: u64,
+}
+
+/// Sends answers to the puzzle server.
+pub trait Submitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> AoCResult<Response>;
+}
+
+/// Submits answers to the configured server ('InputConfig::server_url') using the configured
+/// session cookie.
+pub struct HttpSubmitter {
+    config: InputConfig,
+}
+
+impl HttpSubmitter {
+    pub fn new(config: InputConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> AoCResult<Response> {
+        let url = format!("{}/{}/day/{}/answer",
+                          self.config.server_url.trim_end_matches('/'), year, day);
+        let page = reqwest::blocking::Client::new()
+            .post(&url)
+            .header(COOKIE, format!("session={}", self.config.get_session_cookie()?))
+            .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .map_err(|e| AoCError::IOError(format!(
+                "Submitting answer to '{}' failed: {}", url, e)))?;
+        parse_response(&page)
+    }
+}
+
+/// Returns the response given by the page the server answers a submission with.
+fn parse_response(page: &str) -> AoCResult<Response> {
+    if page.contains("That's the right answer") {
+        Ok(Response::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Response::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Response::TooLow)
+        } else {
+            Ok(Response::Incorrect)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Ok(Response::Wait)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Response::WrongLevel)
+    } else {
+        Err(AoCError::BadInputFormat(format!("Unexpected response to a submission:\n{}", page)))
+    }
+}
+
+/// Submissions of one year, stored as 'answers/year_YYYY_submissions.toml'.
+pub struct History {
+    year: u16,
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct HistoryFile {
+    #[serde(default)]
+    submission: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(year: u16) -> AoCResult<Self> {
+        let path = get_answers_dir().join(format!("year_{}_submissions.toml", year));
+        Self::load_from_file(year, &path)
+    }
+
+    /// Loads the history from the given file. A missing file results in an empty history.
+    pub fn load_from_file(year: u16, path: &Path) -> AoCResult<Self> {
+        let file = match fs::read_to_string(path) {
+            Ok(content) => toml::from_str::<HistoryFile>(&content)
+                .map_err(|e| AoCError::BadInputFormat(format!(
+                    "Parsing submission history '{}' failed: {}", path.display(), e)))?,
+            Err(_) if !path.exists() => HistoryFile::default(),
+            Err(e) => return Err(AoCError::IOError(format!(
+                "Reading submission history '{}' failed: {}", path.display(), e))),
+        };
+        Ok(Self { year, path: path.to_path_buf(), submissions: file.submission })
+    }
+
+    pub fn get_submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Returns why the answer must not be submitted at time 'now' (seconds since the unix
+    /// epoch), if it must not. Answers are refused if the part is already solved, the answer is
+    /// known to be wrong or the last submission was less than 'MIN_INTERVAL' ago.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        if let Some(last) = self.submissions.last() {
+            let next = last.time + MIN_INTERVAL.as_secs();
+            if now < next {
+                return Some(format!("The last answer was submitted {}s ago, wait another {}s.",
+                                    now.saturating_sub(last.time), next - now))
+            }
+        }
+        let submissions = self.submissions.iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+        let value = answer.parse::<i128>().ok();
+        for submission in submissions {
+            let refused = match submission.response {
+                Response::Correct => return Some(format!(
+                    "The part is already solved with '{}'.", submission.answer)),
+                response if response.is_wrong() && submission.answer == answer => true,
+                Response::TooHigh => value.zip(submission.answer.parse::<i128>().ok())
+                    .is_some_and(|(value, too_high)| value >= too_high),
+                Response::TooLow => value.zip(submission.answer.parse::<i128>().ok())
+                    .is_some_and(|(value, too_low)| value <= too_low),
+                _ => false,
+            };
+            if refused {
+                return Some(format!("'{}' is known to be wrong, '{}' was {}.", answer,
+                                    submission.answer, submission.response.get_description()))
+            }
+        }
+        None
+    }
+
+    /// Submits the answer unless 'check(..)' or the known answers refuse it and records the
+    /// response. Correct answers are added to the known answers (not saved).
+    pub fn submit(&mut self, day: u8, part: u8, answer: &str, answers: &mut Answers,
+                  submitter: &dyn Submitter, now: u64) -> AoCResult<Response>
+    {
+        match answers.get(day, part) {
+            Some(known) if known == answer => return Err(AoCError::BadInputFormat(format!(
+                "'{}' is already known to be correct.", answer))),
+            Some(known) => return Err(AoCError::BadInputFormat(format!(
+                "'{}' is known to be wrong, the known answer is '{}'.", answer, known))),
+            None => {}
+        }
+        if let Some(reason) = self.check(day, part, answer, now) {
+            return Err(AoCError::BadInputFormat(format!("Refusing to submit: {}", reason)))
+        }
+        let response = submitter.submit(self.year, day, part, answer)?;
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            response,
+            time: now,
+        });
+        if response == Response::Correct {
+            answers.set(day, part, answer.to_string());
+        }
+        Ok(response)
+    }
+
+    pub fn save(&self) -> AoCResult<()> {
+        let file = HistoryFile { submission: self.submissions.clone() };
+        let content = toml::to_string(&file)
+            .map_err(|e| AoCError::IOError(format!("Serializing submissions failed: {}", e)))?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| AoCError::IOError(format!(
+                    "Creating folder structure '{:?}' failed. {}", parent, e)))?;
+        }
+        fs::write(&self.path, content)
+            .map_err(|e| AoCError::IOError(format!(
+                "Writing to '{}' failed: {}", self.path.display(), e)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use super::*;
+
+    /// Stands in for the puzzle server, knowing only the correct answer.
+    struct StandIn(&'static str);
+
+    impl Submitter for StandIn {
+        fn submit(&self, _: u16, _: u8, _: u8, answer: &str) -> AoCResult<Response> {
+            let answer = answer.parse::<i64>()
+                .map_err(|e| AoCError::BadInputFormat(e.to_string()))?;
+            let correct = self.0.parse::<i64>().expect("Correct answer is a number");
+            Ok(match answer.cmp(&correct) {
+                std::cmp::Ordering::Less => Response::TooLow,
+                std::cmp::Ordering::Equal => Response::Correct,
+                std::cmp::Ordering::Greater => Response::TooHigh,
+            })
+        }
+    }
+
+    fn get_temp_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc_submit_{}.toml", name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn check_submit() -> AoCResult<()> {
+        let path = get_temp_file("check_submit");
+        let mut answers = Answers::load_from_file(&get_temp_file("check_submit_answers"))?;
+        let mut history = History::load_from_file(2015, &path)?;
+        let server = StandIn("42");
+
+        assert_eq!(history.submit(1, 1, "50", &mut answers, &server, 1000)?,
+                   Response::TooHigh);
+        // rate limited
+        assert!(history.submit(1, 1, "30", &mut answers, &server, 1030).is_err());
+        // known to be too high
+        assert!(history.submit(1, 1, "51", &mut answers, &server, 1100).is_err());
+        assert_eq!(history.submit(1, 1, "30", &mut answers, &server, 1100)?,
+                   Response::TooLow);
+        assert!(history.submit(1, 1, "30", &mut answers, &server, 1200).is_err());
+        assert_eq!(history.submit(1, 1, "42", &mut answers, &server, 1200)?,
+                   Response::Correct);
+        assert_eq!(answers.get(1, 1), Some("42"));
+        assert!(history.submit(1, 1, "42", &mut answers, &server, 1300).is_err());
+        assert_eq!(history.get_submissions().len(), 3);
+        history.save()?;
+
+        let history = History::load_from_file(2015, &path)?;
+        assert_eq!(history.get_submissions()[1].response, Response::TooLow);
+        assert!(history.check(1, 2, "42", 1300).is_none());
+        assert!(history.check(1, 1, "43", 1300).is_some());
+        Ok(())
+    }
+
+    /// Serves a single request to the returned URL with the given page. The thread returns the
+    /// request line, headers and body of the request.
+    fn serve_once(page: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Binding a local port failed");
+        let url = format!("http://{}", listener.local_addr().expect("Listener has an address"));
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("Accepting the request failed");
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("Reading the request failed");
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().expect("Content length is a number");
+                }
+                request.push_str(&line);
+                if line.trim().is_empty() {
+                    break
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).expect("Reading the request body failed");
+            request.push_str(&String::from_utf8_lossy(&body));
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\
+                Connection: close\r\n\r\n{}", page.len(), page);
+            reader.get_mut().write_all(response.as_bytes()).expect("Writing the response failed");
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn check_http_submitter() -> AoCResult<()> {
+        let cookie_file = get_temp_file("check_http_submitter_cookie");
+        fs::write(&cookie_file, "abc123").expect("Writing the session cookie failed");
+        let (url, server) = serve_once("<main><article><p>That's not the right answer; your \
+            answer is too high.</p></article></main>");
+        let config = InputConfig {
+            session_cookie_file: cookie_file,
+            session_cookie_env: "AOC_TEST_UNSET_SESSION_COOKIE".to_string(),
+            server_url: url,
+            ..InputConfig::default()
+        };
+        let mut answers = Answers::load_from_file(&get_temp_file("check_http_submitter_answers"))?;
+        let mut history = History::load_from_file(2015, &get_temp_file("check_http_submitter"))?;
+        let submitter = HttpSubmitter::new(config);
+        assert_eq!(history.submit(3, 2, "50", &mut answers, &submitter, 1000)?,
+                   Response::TooHigh);
+        assert!(history.check(3, 2, "60", 2000).is_some());
+        let request = server.join().expect("Stand-in server failed");
+        assert!(request.starts_with("POST /2015/day/3/answer "));
+        assert!(request.contains("session=abc123"));
+        assert!(request.ends_with("level=2&answer=50"));
+        Ok(())
+    }
+
+    #[test]
+    fn check_parse_response() -> AoCResult<()> {
+        assert_eq!(parse_response("<p>That's the right answer!</p>")?, Response::Correct);
+        assert_eq!(parse_response("<p>That's not the right answer; your answer is too low.</p>")?,
+                   Response::TooLow);
+        assert_eq!(parse_response("<p>That's not the right answer.</p>")?, Response::Incorrect);
+        assert_eq!(parse_response("<p>You gave an answer too recently</p>")?, Response::Wait);
+        assert_eq!(parse_response("<p>You don't seem to be solving the right level.</p>")?,
+                   Response::WrongLevel);
+        assert!(parse_response("<p>Please log in</p>").is_err());
+        Ok(())
+    }
+}
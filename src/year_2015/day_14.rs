@@ -1,10 +1,10 @@
 use std::cmp::{max, min, Ordering};
 use crate::errors::AoCError;
+use crate::parsing::{Fields, Pattern};
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
     let mut maximum = 0;
-    for line in input {
-        let reindeer = Reindeer::from(line)?;
+    for reindeer in parse_reindeers(input)? {
         let distance = reindeer.get_distance(2503);
         maximum = max(maximum, distance);
     }
@@ -18,12 +18,8 @@ pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
 }
 
 fn calculate_best_score(input: &[String], rounds: usize) -> Result<usize, AoCError<String>> {
-    let mut reindeers = vec![];
-    let mut scores = vec![];
-    for line in input {
-        reindeers.push(Reindeer::from(line)?);
-        scores.push(0usize);
-    }
+    let mut reindeers = parse_reindeers(input)?;
+    let mut scores = vec![0usize; reindeers.len()];
 
     for _ in 0..rounds {
         let mut max_index = vec![];
@@ -53,6 +49,12 @@ fn calculate_best_score(input: &[String], rounds: usize) -> Result<usize, AoCErr
     Ok(maximum)
 }
 
+fn parse_reindeers(input: &[String]) -> Result<Vec<Reindeer>, AoCError<String>> {
+    let pattern = Pattern::new("{name} can fly {speed} km/s for {fly_time} seconds, but then \
+        must rest for {rest_time} seconds.")?;
+    pattern.parse_lines(input, Reindeer::from)
+}
+
 struct Reindeer {
     speed: usize,
     fly_time: usize,
@@ -62,30 +64,10 @@ struct Reindeer {
 }
 
 impl Reindeer {
-    fn from(str: &str) -> Result<Self, AoCError<String>> {
-        let words: Vec<&str> = str.split(' ').collect();
-        if words.len() != 15 {
-            return Err(AoCError::BadInputFormat(
-                format!("Malformed input line. Expected '<name> can fly <speed> km/s for \
-                <fly_duration> seconds, but then must rest for <rest_duration> seconds.'. Found \
-                '{}'",  str)
-            ))
-        }
-        let speed = words[3].parse().map_err(|_| AoCError::BadInputFormat(
-            format!("Malformed input line. Expected '<name> can fly <speed> km/s for \
-                <fly_duration> seconds, but then must rest for <rest_duration> seconds.'. Found \
-                '{}'",  str)
-        ))?;
-        let fly_time = words[6].parse().map_err(|_| AoCError::BadInputFormat(
-            format!("Malformed input line. Expected '<name> can fly <speed> km/s for \
-                <fly_duration> seconds, but then must rest for <rest_duration> seconds.'. Found \
-                '{}'",  str)
-        ))?;
-        let rest_time = words[13].parse().map_err(|_| AoCError::BadInputFormat(
-            format!("Malformed input line. Expected '<name> can fly <speed> km/s for \
-                <fly_duration> seconds, but then must rest for <rest_duration> seconds.'. Found \
-                '{}'",  str)
-        ))?;
+    fn from(fields: &Fields) -> Result<Self, AoCError<String>> {
+        let speed = fields.get("speed")?;
+        let fly_time = fields.get("fly_time")?;
+        let rest_time = fields.get("rest_time")?;
         let status = Status::Flying(fly_time);
         let distance = 0;
 
@@ -132,12 +114,13 @@ mod test {
 
     #[test]
     fn check_reindeer_get_distance() -> Result<(), AoCError<String>>{
-        let v0 = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
-        let r0 = Reindeer::from(v0)?;
-        assert_eq!(r0.get_distance(1000), 1120);
-        let v1 = "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
-        let r1 = Reindeer::from(v1)?;
-        assert_eq!(r1.get_distance(1000), 1056);
+        let v = [
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.",
+            "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
+        ].map(|line| line.to_string());
+        let reindeers = parse_reindeers(&v)?;
+        assert_eq!(reindeers[0].get_distance(1000), 1120);
+        assert_eq!(reindeers[1].get_distance(1000), 1056);
         Ok(())
     }
 
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::mem::swap;
 use crate::errors::AoCError;
+use crate::geometrics::{Neighborhood, Point2D};
 use crate::output::bool_slice_to_string;
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
@@ -82,38 +83,10 @@ impl Lights {
     }
 
     fn count_neighbors(&self, x: usize, y: usize) -> usize {
-        let mut count = 0;
-        let up = y > 0;
-        let down = y+1 < self.grid.len();
-        let left = x > 0;
-        let right = x+1 < self.grid[y].len();
-
-        if up && self.grid[y-1][x] {
-            count += 1;
-        }
-        if up && left && self.grid[y-1][x-1] {
-            count += 1;
-        }
-        if up && right && self.grid[y-1][x+1] {
-            count += 1;
-        }
-        if left && self.grid[y][x-1] {
-            count += 1;
-        }
-        if right && self.grid[y][x+1] {
-            count += 1;
-        }
-        if down && self.grid[y+1][x] {
-            count += 1;
-        }
-        if down && left && self.grid[y+1][x-1] {
-            count += 1;
-        }
-        if down && right && self.grid[y+1][x+1] {
-            count += 1;
-        }
-
-        count
+        let dimension = Point2D::new(self.grid[y].len(), self.grid.len());
+        Neighborhood::Moore.get_neighbors(&Point2D::new(x, y), &dimension).into_iter()
+            .filter(|neighbor| self.grid[neighbor.y][neighbor.x])
+            .count()
     }
 
     fn count_on(&self) -> usize {
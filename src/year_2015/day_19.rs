@@ -3,14 +3,11 @@ use std::iter::Peekable;
 use std::ops::{Add, Sub};
 use std::str::Chars;
 use crate::errors::AoCError;
+use crate::parsing::split_sections;
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
-    if input.len() < 3 || !input[input.len()-2].is_empty() {
-        return Err(AoCError::UnexpectedInputLength("The input has to be at least 3 lines long and \
-            the penultimate line needs to be empty.".to_string()))
-    }
-    let replacements = parse_replacements(&input[0..input.len()-2])?;
-    let start_str = &input[input.len()-1];
+    let (replacements, start_str) = split_input(input)?;
+    let replacements = parse_replacements(replacements)?;
 
     let mut result_str = HashSet::new();
     for repl in replacements.iter() {
@@ -21,9 +18,10 @@ pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
 }
 
 pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
-    let elems = Elements::from(&input[0..input.len()-2])?;
-    let rules = parse_rules(&input[0..input.len()-2], &elems)?;
-    let goal = Molecule::from_string(&input[input.len()-1], &elems)?;
+    let (replacements, molecule) = split_input(input)?;
+    let elems = Elements::from(replacements)?;
+    let rules = parse_rules(replacements, &elems)?;
+    let goal = Molecule::from_string(molecule, &elems)?;
     let start = Molecule::from_string("e", &elems)?;
 
     let rules_vec: Vec<Molecule> = rules.into_iter().collect();
@@ -35,6 +33,15 @@ pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
     Ok(res.to_string())
 }
 
+fn split_input(input: &[String]) -> Result<(&[String], &String), AoCError<String>> {
+    let [replacements, molecule] = split_sections(input, ["replacements", "molecule"])?;
+    if molecule.len() != 1 {
+        return Err(AoCError::UnexpectedInputLength(
+            "The molecule needs to be a single line.".to_string()))
+    }
+    Ok((replacements, &molecule[0]))
+}
+
 fn parse_replacements(input: &[String]) -> Result<Vec<Replacement>, AoCError<String>> {
     let mut replacements = vec![];
     for line in input {
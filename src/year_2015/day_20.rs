@@ -1,4 +1,4 @@
-use crate::errors::AoCError;
+use crate::errors::{AoCError, ResultExt};
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
     if input.len() != 1 {
@@ -6,8 +6,8 @@ pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
             "Input is expected to be exactly one line. Found: {}", input.len()
         )))
     }
-    let target = input[0].parse::<usize>().map_err(|e| AoCError::BadInputFormat(
-        format!("Could not parse the input number. Found: {}\n{}", input[0], e)))?;
+    let target = input[0].parse::<usize>()
+        .context_with(|| format!("Could not parse the input number. Found: {}", input[0]))?;
 
     let vec = calculate_array(target/10);
     let index = find_first_bigger(&vec, target/10).ok_or_else(
@@ -22,8 +22,8 @@ pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
             "Input is expected to be exactly one line. Found: {}", input.len()
         )))
     }
-    let target = input[0].parse::<usize>().map_err(|e| AoCError::BadInputFormat(
-        format!("Could not parse the input number. Found: {}\n{}", input[0], e)))?;
+    let target = input[0].parse::<usize>()
+        .context_with(|| format!("Could not parse the input number. Found: {}", input[0]))?;
 
     let vec = calculate_array_50(target/11+1);
     let index = find_first_bigger_with_factor(&vec, target, 11).ok_or_else(
@@ -23,10 +23,10 @@ impl SOTAComputer {
     fn from_input(input: &[String], register_a: usize) -> Result<Self, AoCError<String>> {
         let register_b = 0;
         let mut instructions = vec![];
-        for line in input.iter() {
+        for (index, line) in input.iter().enumerate() {
             instructions.push(Instruction::from(line)
-                .ok_or_else(|| AoCError::BadInputFormat(
-                    format!("Parsing instruction failed: '{}'", line)))?);
+                .ok_or_else(|| AoCError::BadInputFormat("Parsing instruction failed.".to_string())
+                    .with_line(index, line))?);
         }
         let ip = Some(0);
         Ok(Self{register_a, register_b, instructions, ip})
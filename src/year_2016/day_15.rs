@@ -1,11 +1,9 @@
 use std::fmt::{Display, Formatter};
 use crate::errors::AoCError;
+use crate::parsing::{Fields, Pattern};
 
-pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
-    let mut disks = vec![];
-    for line in input {
-        disks.push(Disk::parse(line)?);
-    }
+pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
+    let mut disks = parse_disks(input)?;
     disks.sort_unstable_by(|d0, d1| d0.states.cmp(&d1.states));
     disks.reverse();
 
@@ -14,11 +12,8 @@ pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
     Ok(valid_time.to_string())
 }
 
-pub fn part_2(input: &Vec<String>) -> Result<String, AoCError<String>> {
-    let mut disks = vec![];
-    for line in input {
-        disks.push(Disk::parse(line)?);
-    }
+pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
+    let mut disks = parse_disks(input)?;
     disks.push(Disk::new(11, 0, disks.len()+1));
     disks.sort_unstable_by(|d0, d1| d0.states.cmp(&d1.states));
     disks.reverse();
@@ -28,6 +23,12 @@ pub fn part_2(input: &Vec<String>) -> Result<String, AoCError<String>> {
     Ok(valid_time.to_string())
 }
 
+fn parse_disks(input: &[String]) -> Result<Vec<Disk>, AoCError<String>> {
+    let pattern = Pattern::new("Disc #{id} has {positions} positions; at time={time}, it is at \
+        position {position}.")?;
+    pattern.parse_lines(input, Disk::parse)
+}
+
 fn calculate_valid_time(disks: &[Disk]) -> usize {
     let mut time = disks[0].get_first_valid_time();
     loop {
@@ -57,24 +58,16 @@ impl Disk {
         Self{states, start_pos, index}
     }
 
-    pub fn parse(line: &str) -> Result<Self, AoCError<String>> {
-        let words = line.split(' ').collect::<Vec<_>>();
-        if words.len() != 12 {
-            return Err(AoCError::BadInputFormat(format!(
-                "Expected input 'Disc #<disk-index> has <positions> positions; at time=0, it is at \
-                position <start-position>.'. Found '{}'", line)))
+    /// Creates the disk from the fields of a line, turned back to its position at time 0.
+    pub fn parse(fields: &Fields) -> Result<Self, AoCError<String>> {
+        let index = fields.get("id")?;
+        let states: usize = fields.get("positions")?;
+        let time: usize = fields.get("time")?;
+        let position: usize = fields.get("position")?;
+        if states == 0 {
+            return Err(AoCError::BadInputFormat("A disk needs at least one position.".to_string()))
         }
-
-        let index = words[1][1..].parse()
-            .map_err(|e| AoCError::BadInputFormat(
-                format!("Parsing disk index failed: {}", e)))?;
-        let states = words[3].parse()
-            .map_err(|e| AoCError::BadInputFormat(
-                format!("Parsing disk positions failed: {}", e)))?;
-        let start_pos = words[11][0..words[11].len()-1].parse()
-            .map_err(|e| AoCError::BadInputFormat(
-                format!("Parsing starting position failed: {}", e)))?;
-
+        let start_pos = (position + states - time%states)%states;
         Ok(Self{states, start_pos, index})
     }
 
@@ -117,6 +110,20 @@ mod test {
         assert_eq!(part_1(&v), Ok("5".to_string()));
     }
 
+    #[test]
+    fn check_malformed_disks() {
+        let shuffled = vec!["Disc #1 has 5 positions; at position 4, it is at time=0.".to_string()];
+        assert!(part_1(&shuffled)
+            .is_err_and(|e| matches!(e.get_inner(), AoCError::BadInputFormat(_))));
+        let truncated = vec!["Disc #1 has 5 positions; at time=0".to_string()];
+        assert!(part_1(&truncated).is_err());
+        let later = vec![
+            "Disc #1 has 5 positions; at time=3, it is at position 2.".to_string(),
+            "Disc #2 has 2 positions; at time=0, it is at position 1.".to_string(),
+        ];
+        assert_eq!(part_1(&later), Ok("5".to_string()));
+    }
+
     #[test]
     fn check_input_part_1() -> Result<(), AoCError<String>> {
         let input = get_input(2016, 15)?;
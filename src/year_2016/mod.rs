@@ -1,3 +1,5 @@
+use crate::solution::{register_days, Solution};
+
 pub mod lib_2016;
 
 pub mod day_01;
@@ -25,3 +27,33 @@ pub mod day_22;
 pub mod day_23;
 pub mod day_24;
 pub mod day_25;
+
+pub fn solutions() -> Vec<Solution> {
+    register_days!(2016,
+        1 => day_01,
+        2 => day_02,
+        3 => day_03,
+        4 => day_04,
+        5 => day_05 (exclusive),
+        6 => day_06,
+        7 => day_07,
+        8 => day_08,
+        9 => day_09,
+        10 => day_10,
+        11 => day_11,
+        12 => day_12,
+        13 => day_13,
+        14 => day_14 (exclusive),
+        15 => day_15,
+        16 => day_16,
+        17 => day_17,
+        18 => day_18,
+        19 => day_19,
+        20 => day_20,
+        21 => day_21,
+        22 => day_22,
+        23 => day_23,
+        24 => day_24,
+        25 => day_25,
+    )
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use crate::errors::AoCError;
+use crate::geometrics::{Neighborhood, Point2D};
 
 pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
     if input.len() != 1 {
@@ -55,13 +56,13 @@ fn calculate_ring_position(square: usize, ring_index: usize) -> usize {
 
 fn stress_test(max_value: usize) -> usize {
     let mut values = HashMap::new();
-    let mut point = (0, 0);
+    let mut point = Point::new(0, 0);
     let mut dir = Direction::new();
     values.insert(point, 1);
 
     loop {
         (dir, point) = dir.next(&point);
-        let value = get_neighbors(&point).into_iter()
+        let value = Neighborhood::Moore.get_all_neighbors(&point).into_iter()
             .map(|p| *values.get(&p).unwrap_or(&0))
             .sum();
         if value > max_value {
@@ -72,20 +73,7 @@ fn stress_test(max_value: usize) -> usize {
 
 }
 
-fn get_neighbors(p: &Point) -> Vec<Point> {
-    vec![
-        (p.0+1, p.1+1),
-        (p.0+1, p.1  ),
-        (p.0+1, p.1-1),
-        (p.0  , p.1+1),
-        (p.0  , p.1-1),
-        (p.0-1, p.1+1),
-        (p.0-1, p.1  ),
-        (p.0-1, p.1-1),
-    ]
-}
-
-type Point = (i32, i32);
+type Point = Point2D<i32>;
 
 #[derive(Copy, Clone)]
 enum Direction {
@@ -108,7 +96,7 @@ impl Direction {
                     new_dir.next(point)
                 } else {
                     let new_dir = Direction::Up(*total, remaining-1);
-                    let new_point = (point.0, point.1-1);
+                    let new_point = Point::new(point.x, point.y-1);
                     (new_dir, new_point)
                 }
             }
@@ -118,7 +106,7 @@ impl Direction {
                     new_dir.next(point)
                 } else {
                     let new_dir = Direction::Down(*total, remaining-1);
-                    let new_point = (point.0, point.1+1);
+                    let new_point = Point::new(point.x, point.y+1);
                     (new_dir, new_point)
                 }
             }
@@ -128,7 +116,7 @@ impl Direction {
                     new_dir.next(point)
                 } else {
                     let new_dir = Direction::Right(*total, remaining-1);
-                    let new_point = (point.0+1, point.1);
+                    let new_point = Point::new(point.x+1, point.y);
                     (new_dir, new_point)
                 }
             }
@@ -138,7 +126,7 @@ impl Direction {
                     new_dir.next(point)
                 } else {
                     let new_dir = Direction::Lef(*total, remaining-1);
-                    let new_point = (point.0-1, point.1);
+                    let new_point = Point::new(point.x-1, point.y);
                     (new_dir, new_point)
                 }
             }
@@ -1,5 +1,6 @@
-use std::cmp::{max, min};
+use std::cmp::max;
 use crate::errors::AoCError;
+use crate::hex::{FlatDirection, Hex};
 
 pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
     if input.len() != 1 {
@@ -18,82 +19,25 @@ pub fn part_2(input: &Vec<String>) -> Result<String, AoCError<String>> {
 }
 
 fn count_directions(line: &str) -> Result<usize, AoCError<String>> {
-    let mut directions = [0usize;6];
-    for direction in line
-        .split(',').map(Direction::parse) {
-        directions[direction?.to_index()] += 1;
-    }
-    while eliminate_opposing(&mut directions) | eliminate_adjacent(&mut directions) {}
-    Ok(directions.iter().sum())
+    let position = parse_moves(line)?.into_iter()
+        .fold(Hex::<i64>::default(), |position, direction| position + direction.get_offset());
+    Ok(position.get_length() as usize)
 }
 
 fn find_furthest(line: &str) -> Result<usize, AoCError<String>> {
     let mut max_distance = 0;
-    let mut directions = [0usize;6];
-    for direction in line
-        .split(',').map(Direction::parse) {
-        directions[direction?.to_index()] += 1;
-        while eliminate_opposing(&mut directions) | eliminate_adjacent(&mut directions) {}
-        max_distance = max(max_distance, directions.iter().sum())
+    let mut position = Hex::<i64>::default();
+    for direction in parse_moves(line)? {
+        position += direction.get_offset();
+        max_distance = max(max_distance, position.get_length() as usize)
     }
     Ok(max_distance)
 }
 
-fn eliminate_opposing(directions: &mut [usize; 6]) -> bool {
-    let mut changed = false;
-    for index in 0..3 {
-        let redundant = min(directions[index], directions[index+3]);
-        if redundant > 0 {
-            changed = true;
-            directions[index] -= redundant;
-            directions[index+3] -= redundant;
-        }
-    }
-    changed
-}
-
-fn eliminate_adjacent(directions: &mut [usize; 6]) -> bool {
-    let mut changed = false;
-    for index in 0..6 {
-        let redundant = min(directions[index], directions[(index+2)%6]);
-        if redundant > 0 {
-            changed = true;
-            directions[index] -= redundant;
-            directions[(index+2)%6] -= redundant;
-            directions[(index+1)%6] += redundant;
-        }
-    }
-    changed
-}
-
-enum Direction {
-    North, NorthEast, SouthEast, South, SouthWest, NorthWest,
-}
-
-impl Direction {
-    fn parse(str: &str) -> Result<Self, AoCError<String>> {
-        match str {
-            "n" => Ok(Self::North),
-            "ne" => Ok(Self::NorthEast),
-            "se" => Ok(Self::SouthEast),
-            "s" => Ok(Self::South),
-            "sw" => Ok(Self::SouthWest),
-            "nw" => Ok(Self::NorthWest),
-            _ => Err(AoCError::BadInputFormat(format!(
-                "Unknown direction '{}'. Only 'n', 'ne', 'se', 's', 'sw' and 'nw allowed.", str))),
-        }
-    }
-
-    fn to_index(&self) -> usize {
-        match self {
-            Direction::North => 0,
-            Direction::NorthEast => 1,
-            Direction::SouthEast => 2,
-            Direction::South => 3,
-            Direction::SouthWest => 4,
-            Direction::NorthWest => 5,
-        }
-    }
+fn parse_moves(line: &str) -> Result<Vec<FlatDirection>, AoCError<String>> {
+    line.split(',')
+        .map(FlatDirection::parse)
+        .collect()
 }
 
 #[cfg(test)]
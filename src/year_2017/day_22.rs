@@ -1,5 +1,5 @@
-use std::collections::HashMap;
 use crate::errors::AoCError;
+use crate::geometrics::{impl_parsable, Grid, Point2D, SparseGrid};
 
 pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
     let cluster = parse_input(input)?;
@@ -12,32 +12,16 @@ pub fn part_2(input: &Vec<String>) -> Result<String, AoCError<String>> {
 }
 
 fn parse_input(input: &Vec<String>) -> Result<Cluster, AoCError<String>> {
-    let x_offset = if input[0].len()%2 == 1 {
-        input[0].len()/2
-    } else {
+    let grid = Grid::parse(input)?;
+    let size = grid.get_dimension();
+    if size.x%2 != 1 {
         return Err(AoCError::BadInputFormat("Lines must have odd length.".to_string()))
-    };
-    let y_offset = if input.len()%2 == 1 {
-        input.len()/2
-    } else {
+    }
+    if size.y%2 != 1 {
         return Err(AoCError::BadInputFormat("Input must have odd number of lines".to_string()))
-    };
-    let mut cluster = Cluster::new();
-    for (line_index, line) in input.iter().enumerate() {
-        for (col_index, elem) in line.chars().enumerate() {
-            match elem {
-                '.' => {}
-                '#' => {
-                    let point = (col_index as i32 - x_offset as i32,
-                                 line_index as i32 - y_offset as i32);
-                    cluster.insert(point, Status::Infected);
-                }
-                c => return Err(AoCError::BadInputFormat(format!(
-                    "Unsupported character '{}'. Only '.' and #' supported.", c)))
-            }
-        }
     }
-    Ok(cluster)
+    let origin = Point::new(-(size.x as i64/2), -(size.y as i64/2));
+    Ok(SparseGrid::from_grid(&grid, Status::Clean, origin))
 }
 
 fn iterate_steps(mut cluster: Cluster, iterations: usize, complex: bool) -> usize {
@@ -48,8 +32,8 @@ fn iterate_steps(mut cluster: Cluster, iterations: usize, complex: bool) -> usiz
     position.infection_counter
 }
 
-type Point = (i32, i32);
-type Cluster = HashMap<Point, Status>;
+type Point = Point2D<i64>;
+type Cluster = SparseGrid<Status>;
 
 struct Position {
     point: Point,
@@ -59,14 +43,14 @@ struct Position {
 
 impl Position {
     fn new() -> Self {
-        let point = (0, 0);
+        let point = Point::new(0, 0);
         let direction = Direction::Up;
         let infection_counter = 0;
         Self {point, direction, infection_counter}
     }
 
     fn step(&mut self, cluster: &mut Cluster, complex_mode: bool) {
-        let status = cluster.get(&self.point).unwrap_or(&Status::Clean);
+        let status = cluster.get_tile(&self.point);
         self.direction = self.direction.turn_status_based(status);
         let new_status = if complex_mode {
             status.toggle_complex()
@@ -76,11 +60,7 @@ impl Position {
         if new_status == Status::Infected {
             self.infection_counter += 1;
         }
-        if new_status == Status::Clean {
-            cluster.remove(&self.point);
-        } else {
-            cluster.insert(self.point, new_status);
-        }
+        cluster.set_tile(&self.point, new_status);
         self.point = self.direction.move_point(&self.point)
     }
 }
@@ -93,10 +73,10 @@ enum Direction {
 impl Direction {
     fn move_point(&self, point: &Point) -> Point {
         match self {
-            Direction::Up =>       (point.0,   point.1-1),
-            Direction::Right =>    (point.0+1, point.1),
-            Direction::Down =>     (point.0,   point.1+1),
-            Direction::Left =>     (point.0-1, point.1),
+            Direction::Up =>       Point::new(point.x,   point.y-1),
+            Direction::Right =>    Point::new(point.x+1, point.y),
+            Direction::Down =>     Point::new(point.x,   point.y+1),
+            Direction::Left =>     Point::new(point.x-1, point.y),
         }
     }
 
@@ -128,11 +108,16 @@ impl Direction {
     }
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Status {
     Clean, Weakened, Infected, Flagged,
 }
 
+impl_parsable!(Status {
+    '.' => Self::Clean,
+    '#' => Self::Infected,
+});
+
 impl Status {
     fn toggle_simple(&self) -> Self {
         match self {
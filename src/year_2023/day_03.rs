@@ -1,5 +1,6 @@
 use std::fmt::{Display, Formatter};
 use crate::errors::AoCError;
+use crate::geometrics::{Neighborhood, Point2D};
 
 pub fn part_1(input: &Vec<String>) -> Result<String, AoCError<String>> {
     let schematic = Schematic::parse(input)?;
@@ -60,35 +61,11 @@ impl Schematic {
 
     fn get_surrounding_numbers(&mut self, point: Point) -> Vec<u32> {
         let mut numbers = vec![];
-        let x_start = if point.0 > 0 {
-            point.0 - 1
-        } else {
-            0
-        };
-        let x_end = if point.0+1 < self.grid[0].len() {
-            point.0 + 1
-        } else {
-            point.0
-        };
-        let y_start = if point.1 > 0 {
-            point.1 - 1
-        } else {
-            0
-        };
-        let y_end = if point.1+1 < self.grid.len() {
-            point.1 + 1
-        } else {
-            point.1
-        };
-        for y in y_start..=y_end {
-            for x in x_start..=x_end {
-                if x == point.0 && y == point.1 {
-                    continue
-                }
-                if let Symbol::Digit(_) = self.grid[y][x] {
-                    let start = self.find_number_start((x, y));
-                    numbers.push(self.get_number_from_start(start));
-                }
+        let dimension = Point2D::new(self.grid[0].len(), self.grid.len());
+        for neighbor in Neighborhood::Moore.get_neighbors(&point.into(), &dimension) {
+            if let Symbol::Digit(_) = self.grid[neighbor.y][neighbor.x] {
+                let start = self.find_number_start(neighbor.into());
+                numbers.push(self.get_number_from_start(start));
             }
         }
         numbers
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use crate::errors::AoCError;
-use crate::geometrics::{Direction, Grid, Parsable};
+use crate::geometrics::{impl_parsable, Direction, Grid};
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
     let mut grid = Grid::parse(input)?;
@@ -15,10 +15,10 @@ pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
     Ok(grid.sum_rows().to_string())
 }
 
-type Point = crate::geometrics::Point<usize>;
+type Point = crate::geometrics::Point2D<usize>;
 
 impl Grid<Tile> {
-    fn get_rounded(&self) -> Vec<(usize, usize)> {
+    fn get_rounded(&self) -> Vec<Point> {
         self.get_all_positions_of(&Tile::Rounded)
     }
 
@@ -60,20 +60,20 @@ impl Grid<Tile> {
     fn roll_direction(&mut self, dir: Direction) {
         let size = self.get_dimension();
         let row_range = if dir == Direction::South {
-            itertools::Either::Left((0..size.1).rev())
+            itertools::Either::Left((0..size.y).rev())
         } else {
-            itertools::Either::Right(0..size.1)
+            itertools::Either::Right(0..size.y)
         };
         let col_range =if dir == Direction::East {
-            itertools::Either::Left((0..size.0).rev())
+            itertools::Either::Left((0..size.x).rev())
         } else {
-            itertools::Either::Right(0..size.0)
+            itertools::Either::Right(0..size.x)
         };
 
         for y in row_range {
             for x in col_range.clone() {
-                let point = (x, y);
-                if let Some(&Tile::Rounded) = self.get_tile(&(x, y)) {
+                let point = Point::new(x, y);
+                if let Some(&Tile::Rounded) = self.get_tile(&point) {
                     let mut target = point;
                     while let Some(next_point) = dir.move_point(&target) {
                         if self.get_tile(&next_point) != Some(&Tile::Empty) {
@@ -91,7 +91,7 @@ impl Grid<Tile> {
 
     fn sum_rows(&self) -> usize {
         let size = self.get_dimension();
-        let nums = self.iter().zip((1..=size.1).rev())
+        let nums = self.iter().zip((1..=size.y).rev())
             .map(|(row, index)| row.iter()
                 .filter(|elem| **elem == Tile::Rounded)
                 .count()*index)
@@ -107,17 +107,11 @@ enum Tile {
     Empty,
 }
 
-impl Parsable for Tile {
-    fn parse(c: char) -> Result<Self, AoCError<String>> {
-        match c {
-            '#' => Ok(Self::Square),
-            'O' => Ok(Self::Rounded),
-            '.' => Ok(Self::Empty),
-            c => Err(AoCError::BadInputFormat(
-                format!("Only '#', 'O' and '.' are supported as tiles. Found '{}'", c))),
-        }
-    }
-}
+impl_parsable!(Tile {
+    '#' => Self::Square,
+    'O' => Self::Rounded,
+    '.' => Self::Empty,
+});
 
 impl Display for Tile {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -1,11 +1,11 @@
 use std::cmp::max;
 use std::collections::HashSet;
-use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Direction, Grid, Parsable};
+use crate::errors::AoCError;
+use crate::geometrics::{impl_parsable, Direction, Grid};
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
     let grid = Grid::parse(input)?;
-    Ok(grid.follow_all_paths((0, 0), Direction::East).to_string())
+    Ok(grid.follow_all_paths(Point::new(0, 0), Direction::East).to_string())
 }
 
 pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
@@ -13,7 +13,7 @@ pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
     Ok(grid.follow_optimum_path().to_string())
 }
 
-type Point = crate::geometrics::Point<usize>;
+type Point = crate::geometrics::Point2D<usize>;
 
 impl Grid<Tile> {
     fn follow_all_paths(&self, start_pos: Point, start_dir: Direction) -> usize {
@@ -39,19 +39,19 @@ impl Grid<Tile> {
     fn follow_optimum_path(&self) -> usize {
         let mut maximum = 0;
         let size = self.get_dimension();
-        for row in 0..size.1 {
-            let start = (0, row);
+        for row in 0..size.y {
+            let start = Point::new(0, row);
             maximum = max(maximum,
                           self.follow_all_paths(start, Direction::East));
-            let start =(size.1-1, row);
+            let start = Point::new(size.x-1, row);
             maximum = max(maximum,
                           self.follow_all_paths(start, Direction::West));
         }
-        for col in 0..size.0 {
-            let start = (col, 0);
+        for col in 0..size.x {
+            let start = Point::new(col, 0);
             maximum = max(maximum,
                           self.follow_all_paths(start, Direction::South));
-            let start =(col, size.0-1);
+            let start = Point::new(col, size.y-1);
             maximum = max(maximum,
                           self.follow_all_paths(start, Direction::North));
         }
@@ -122,19 +122,13 @@ impl Tile {
     }
 }
 
-impl Parsable for Tile {
-    fn parse(c: char) -> AoCResult<Self> {
-        match c {
-            '.' => Ok(Self::Empty),
-            '-' => Ok(Self::SplitterHorizontal),
-            '|' => Ok(Self::SplitterVertical),
-            '/' => Ok(Self::MirrorTopRight),
-            '\\' => Ok(Self::MirrorTopLeft),
-            c => Err(AoCError::BadInputFormat(
-                format!("Only '.', '-', '|', '/' and '\\' supported. Found '{}'", c))),
-        }
-    }
-}
+impl_parsable!(Tile {
+    '.' => Self::Empty,
+    '-' => Self::SplitterHorizontal,
+    '|' => Self::SplitterVertical,
+    '/' => Self::MirrorTopRight,
+    '\\' => Self::MirrorTopLeft,
+});
 
 impl Direction {
     fn mirror_top_left(&self) -> Self {
@@ -195,6 +189,15 @@ mod test {
         assert_eq!(part_2(&input), Ok("51".to_string()));
     }
 
+    #[test]
+    fn check_edge_starts() {
+        // only the beams entering from the east and from the south energize every tile
+        let row = vec!["|...".to_string()];
+        assert_eq!(part_2(&row), Ok("4".to_string()));
+        let column = ["-", ".", ".", "."].map(|line| line.to_string());
+        assert_eq!(part_2(&column), Ok("4".to_string()));
+    }
+
     #[test]
     fn check_input_part_2() -> Result<(), AoCError<String>> {
         let input = get_input(2023, 16)?;
@@ -5,9 +5,9 @@ use crate::geometrics::{Direction, Grid};
 
 pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
     let grid = Grid::parse_digits(input)?;
-    let start = (0, 0);
+    let start = Point::new(0, 0);
     let size = grid.get_dimension();
-    let end = (size.0-1, size.1-1);
+    let end = Point::new(size.x-1, size.y-1);
     assert!(grid.get_tile(&end).is_some());
     grid.get_shortest_path(start, end, 0, 3)
         .map(|v| v.to_string())
@@ -15,15 +15,15 @@ pub fn part_1(input: &[String]) -> Result<String, AoCError<String>> {
 
 pub fn part_2(input: &[String]) -> Result<String, AoCError<String>> {
     let grid = Grid::parse_digits(input)?;
-    let start = (0, 0);
+    let start = Point::new(0, 0);
     let size = grid.get_dimension();
-    let end = (size.0-1, size.1-1);
+    let end = Point::new(size.x-1, size.y-1);
     assert!(grid.get_tile(&end).is_some());
     grid.get_shortest_path(start, end, 4, 10)
         .map(|v| v.to_string())
 }
 
-type Point = crate::geometrics::Point<usize>;
+type Point = crate::geometrics::Point2D<usize>;
 
 impl Grid<u8> {
     fn get_shortest_path(&self, start: Point, end: Point, min_to_turn: usize, max_to_turn: usize)
@@ -70,8 +70,7 @@ impl Grid<u8> {
                 .for_each(|elem| heap.push(Reverse(elem)));
         }
         Err(AoCError::NoSolutionFoundError(
-            format!("Could not find path from ({}, {}) to ({}, {})",
-                    start.0, start.1, end.0, end.1)))
+            format!("Could not find path from {} to {}", start, end)))
     }
 }
 
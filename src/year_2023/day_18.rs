@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Direction, Point};
+use crate::geometrics::{Direction, Point2D};
 
 pub fn part_1(input: &[String]) -> AoCResult<String> {
     let path = Path::from_instructions(input, false)?;
@@ -13,7 +13,7 @@ pub fn part_2(input: &[String]) -> AoCResult<String> {
 }
 
 struct Path {
-    corners: Vec<Point<i32>>,
+    corners: Vec<Point2D<i32>>,
     len: usize,
 }
 
@@ -23,7 +23,7 @@ impl Path {
         positions.insert((0, 0));
         Self {
             //positions,
-            corners: vec![(0, 0)],
+            corners: vec![Point2D::new(0, 0)],
             len: 0,
             //current: (0, 0),
             //min: (0, 0),
@@ -33,7 +33,7 @@ impl Path {
 
     fn from_instructions(input: &[String], parse_color: bool) -> AoCResult<Self> {
         let mut path = Self::new();
-        let mut current = (0, 0);
+        let mut current = Point2D::new(0, 0);
         for line in input {
             let (mut dir, mut steps, color) = Self::parse_instruction(line)?;
             if parse_color {
@@ -108,7 +108,7 @@ impl Path {
         for index in 0..len {
             let curr_corner = self.corners[index];
             let next_corner = self.corners[(index+1)%len];
-            sum += (curr_corner.1+next_corner.1) as i64 * (curr_corner.0-next_corner.0) as i64;
+            sum += (curr_corner.y+next_corner.y) as i64 * (curr_corner.x-next_corner.x) as i64;
         }
         assert_eq!(sum%2, 0);
         assert!(sum > 0);
@@ -1,7 +1,6 @@
-use std::cmp::max;
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashMap;
 use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Direction, Grid, Parsable, Point};
+use crate::geometrics::{impl_parsable, Direction, Grid, GridView, Neighborhood, Point2D};
 
 pub fn part_1(input: &[String]) -> AoCResult<String> {
     let grid = Grid::parse(input)?;
@@ -33,75 +32,97 @@ pub fn part_2(input: &[String]) -> AoCResult<String> {
         return Err(AoCError::NoSolutionFoundError(
             "Faulty constraint: Expected a rock free border around the grid.".to_string()))
     }
-    Ok(reachable_wrapping(&grid, start, 26501365).to_string())
+    Ok(reachable_wrapping(&grid, start, 26501365)?.to_string())
 }
 
-fn get_valid_neighbors(grid: &Grid<Tile>, pos: Point<usize>) -> Vec<Point<usize>> {
-    Direction::get_all_neighbors(&pos).into_iter()
-        .filter(|point| {
-            if let Some(tile) = grid.get_tile(point) {
-                tile != &Tile::Rock
-            } else {
-                false
-            }
-        })
-        .collect()
+fn get_reachable(grid: &Grid<Tile>, start: Point2D<usize>, steps: usize) -> usize {
+    count_reachable(&get_distances(grid, start, steps), steps)
 }
 
-fn get_reachable(grid: &Grid<Tile>, start: Point<usize>, steps: usize) -> usize {
-    let mut reachable_even = HashSet::new();
-    let mut reachable_odd = HashSet::new();
+/// Counts the positions which can be reached in exactly 'steps' steps. Going back and forth, these
+/// are all positions at most 'steps' away with the same parity.
+fn count_reachable<P>(distances: &HashMap<P, usize>, steps: usize) -> usize {
+    distances.values()
+        .filter(|distance| **distance <= steps && *distance%2 == steps%2)
+        .count()
+}
 
-    let mut pq = VecDeque::new();
-    pq.push_back((start, 0usize));
-    while let Some((curr_point, curr_steps)) = pq.pop_front() {
-        if curr_steps > steps {
-            break;
-        }
-        let reachable = if curr_steps%2 == 0 {
-            &mut reachable_even
-        } else {
-            &mut reachable_odd
-        };
-        if reachable.contains(&curr_point) {
-            continue
+fn get_distances(grid: &Grid<Tile>, start: Point2D<usize>, steps: usize)
+    -> HashMap<Point2D<usize>, usize>
+{
+    grid.get_distances(start, &Neighborhood::VonNeumann, steps, |tile| tile != &Tile::Rock)
+}
+
+/// Returns the number of positions at each distance from 'start' in the infinitely repeated
+/// garden, up to 'steps'. All positions in reach fit into a square around 'start', which is used
+/// to mark them as seen instead of storing the distance of every position.
+fn get_wrapping_layer_sizes(grid: &Grid<Tile>, start: Point2D<usize>, steps: usize)
+    -> Vec<usize>
+{
+    let wrapping = grid.wrapping();
+    let start = Point2D::new(start.x as i64, start.y as i64);
+    let width = 2*steps+1;
+    let get_index = |pos: &Point2D<i64>| {
+        let offset = *pos - start + Point2D::new(steps as i64, steps as i64);
+        offset.y as usize*width + offset.x as usize
+    };
+    let mut seen = vec![false; width*width];
+    seen[get_index(&start)] = true;
+    let mut layer = vec![start];
+    let mut sizes = vec![1];
+    while sizes.len() <= steps && !layer.is_empty() {
+        let mut next = vec![];
+        for pos in layer.iter() {
+            for neighbor in wrapping.get_neighbors(pos, &Neighborhood::VonNeumann) {
+                let index = get_index(&neighbor);
+                if !seen[index] &&
+                    wrapping.get_tile(&neighbor).is_some_and(|tile| tile != &Tile::Rock)
+                {
+                    seen[index] = true;
+                    next.push(neighbor);
+                }
+            }
         }
-        reachable.insert(curr_point);
-        get_valid_neighbors(grid, curr_point).into_iter()
-            .for_each(|point| pq.push_back((point, curr_steps+1)));
-    }
-    if steps%2 == 0 {
-        reachable_even.len()
-    } else {
-        reachable_odd.len()
+        sizes.push(next.len());
+        layer = next;
     }
+    sizes
 }
 
-fn check_start_and_size_constrain(grid: &Grid<Tile>, start: Point<usize>) -> bool {
+/// Sums up the layers which can be reached in exactly 'steps' steps, see 'count_reachable(..)'.
+fn sum_reachable(layer_sizes: &[usize], steps: usize) -> usize {
+    layer_sizes.iter()
+        .take(steps+1)
+        .skip(steps%2)
+        .step_by(2)
+        .sum()
+}
+
+fn check_start_and_size_constrain(grid: &Grid<Tile>, start: Point2D<usize>) -> bool {
     let size = grid.get_dimension();
-    if size.0 != size.1 {
+    if size.x != size.y {
         return false
     }
-    if size.0%2 != 1 {
+    if size.x%2 != 1 {
         return false
     }
-    if (size.0-1)/2 != start.0 {
+    if (size.x-1)/2 != start.x {
         return false
     }
-    if (size.1-1)/2 != start.1 {
+    if (size.y-1)/2 != start.y {
         return false
     }
     true
 }
 
-fn check_strait_line_constrain(grid: &Grid<Tile>, start: Point<usize>) -> bool {
+fn check_strait_line_constrain(grid: &Grid<Tile>, start: Point2D<usize>) -> bool {
     check_line_constrain_dir(grid, start, Direction::North) &&
         check_line_constrain_dir(grid, start, Direction::East) &&
         check_line_constrain_dir(grid, start, Direction::South) &&
         check_line_constrain_dir(grid, start, Direction::West)
 }
 
-fn check_line_constrain_dir(grid: &Grid<Tile>, start: Point<usize>, dir: Direction) -> bool {
+fn check_line_constrain_dir(grid: &Grid<Tile>, start: Point2D<usize>, dir: Direction) -> bool {
     let mut current = start;
     while let Some(next) = dir.move_point(&current) {
         if let Some(tile) = grid.get_tile(&next) {
@@ -118,15 +139,15 @@ fn check_line_constrain_dir(grid: &Grid<Tile>, start: Point<usize>, dir: Directi
 
 fn check_empty_border_constrain(grid: &Grid<Tile>) -> bool {
     let size = grid.get_dimension();
-    for x in 0..size.0 {
-        if let Some(tile) = grid.get_tile(&(x,0)) {
+    for x in 0..size.x {
+        if let Some(tile) = grid.get_tile(&Point2D::new(x, 0)) {
             if tile == &Tile::Rock {
                 return false
             }
         } else {
             return false
         }
-        if let Some(tile) = grid.get_tile(&(x,size.1-1)) {
+        if let Some(tile) = grid.get_tile(&Point2D::new(x, size.y-1)) {
             if tile == &Tile::Rock {
                 return false
             }
@@ -134,15 +155,15 @@ fn check_empty_border_constrain(grid: &Grid<Tile>) -> bool {
             return false
         }
     }
-    for y in 0..size.1 {
-        if let Some(tile) = grid.get_tile(&(0,y)) {
+    for y in 0..size.y {
+        if let Some(tile) = grid.get_tile(&Point2D::new(0, y)) {
             if tile == &Tile::Rock {
                 return false
             }
         } else {
             return false
         }
-        if let Some(tile) = grid.get_tile(&(size.0-1,y)) {
+        if let Some(tile) = grid.get_tile(&Point2D::new(size.x-1, y)) {
             if tile == &Tile::Rock {
                 return false
             }
@@ -153,143 +174,28 @@ fn check_empty_border_constrain(grid: &Grid<Tile>) -> bool {
     true
 }
 
-fn reachable_wrapping(grid: &Grid<Tile>, start: Point<usize>, steps: usize) -> usize {
-    assert_eq!(start.0, start.1);
-    let steps_to_corner = start.0+start.1;
-    let steps_to_border = start.0;
-    let center_values = get_values_from_start(grid, start);
-    let mut sum = if steps%2 == 0 {
-        center_values.even_reachable
-    } else {
-        center_values.odd_reachable
-    };
-    for dir in Direction::get_all_directions() {
-        let tmp = reachable_wrapping_direction(grid, steps.saturating_sub(steps_to_border+1), dir);
-        sum += tmp;
+/// Counts the tiles reachable in the infinitely repeated garden. With the constraints checked in
+/// 'part_2(..)' the count grows quadratically with every second crossing of the garden, so it is
+/// extrapolated from three counts on the wrapping grid. Few steps are counted directly.
+fn reachable_wrapping(grid: &Grid<Tile>, start: Point2D<usize>, steps: usize)
+    -> AoCResult<usize>
+{
+    let size = grid.get_dimension().x;
+    let crossings = steps/size;
+    let first = crossings%2;
+    let samples = [first, first+2, first+4].map(|crossings| steps%size + crossings*size);
+    if steps <= samples[2] {
+        return Ok(sum_reachable(&get_wrapping_layer_sizes(grid, start, steps), steps))
     }
-    for dir in CornerDirection::get_all_directions() {
-        let tmp = reachable_wrapping_corner(grid, steps.saturating_sub(steps_to_corner+2), dir);
-        sum += tmp;
+    let layer_sizes = get_wrapping_layer_sizes(grid, start, samples[2]);
+    let [a, b, c] = samples.map(|sample| sum_reachable(&layer_sizes, sample) as i64);
+    let (growth, acceleration) = (b-a, c-2*b+a);
+    if growth < 0 || acceleration < 0 {
+        return Err(AoCError::NoSolutionFoundError(format!(
+            "The reachable tiles ({}, {}, {}) do not grow quadratically.", a, b, c)))
     }
-    sum
-}
-
-fn reachable_wrapping_direction(grid: &Grid<Tile>, steps: usize, dir: Direction) -> usize {
-    let steps = steps as i32;
-    let size = grid.get_dimension();
-    let start = match dir {
-        Direction::North => (size.0/2, size.1-1),
-        Direction::East => (0, size.1/2),
-        Direction::South => (size.0/2, 0),
-        Direction::West => (size.0-1, size.1/2),
-    };
-    let values = get_values_from_start(grid, start);
-    let steps_to_cross = size.0 as i32;
-    assert_eq!(size.0, size.1);
-
-    let mut sum = 0;
-    let mut remaining = steps;
-    while remaining >= 0 {
-        if remaining as usize >= values.complete_fill {
-            if remaining%2 == 0 {
-                sum += values.even_reachable;
-            } else {
-                sum += values.odd_reachable;
-            }
-        } else {
-            let tmp = get_reachable(grid, start, remaining as usize);
-            sum += tmp;
-        }
-        remaining -= steps_to_cross;
-    }
-    sum
-}
-
-fn reachable_wrapping_corner(grid: &Grid<Tile>, steps: usize, dir: CornerDirection) -> usize {
-    let steps = steps as i32;
-    let size = grid.get_dimension();
-    let start = match dir {
-        CornerDirection::NorthEast => (0, size.1-1),
-        CornerDirection::SouthEast => (0, 0),
-        CornerDirection::SouthWest => (size.0-1, 0),
-        CornerDirection::NorthWest => (size.0-1, size.1-1),
-    };
-    let values = get_values_from_start(grid, start);
-    let steps_to_next_diagonal = size.0 as i32;
-    assert_eq!(size.0, size.1);
-
-    let mut sum = 0;
-    let mut remaining = steps;
-    let mut block_count = 1;
-    while remaining >= 0 {
-        if remaining as usize >= values.complete_fill {
-            if remaining%2 == 0 {
-                sum += values.even_reachable*block_count;
-            } else {
-                sum += values.odd_reachable*block_count;
-            }
-        } else {
-            let tmp = get_reachable(grid, start, remaining as usize);
-            sum += tmp*block_count;
-        }
-        remaining -= steps_to_next_diagonal;
-        block_count += 1;
-    }
-    sum
-}
-
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-enum CornerDirection {
-    NorthEast,
-    SouthEast,
-    SouthWest,
-    NorthWest,
-}
-
-impl CornerDirection {
-    fn get_all_directions() -> Vec<Self> {
-        vec![
-            Self::NorthEast,
-            Self::SouthEast,
-            Self::SouthWest,
-            Self::NorthWest,
-        ]
-    }
-}
-
-fn get_values_from_start(grid: &Grid<Tile>, start: Point<usize>) -> Values {
-    let mut reachable_even = HashSet::new();
-    let mut reachable_odd = HashSet::new();
-
-    let mut pq = VecDeque::new();
-    let mut max_step = 0;
-    pq.push_back((start, 0usize));
-    while let Some((curr_point, curr_steps)) = pq.pop_front() {
-        let reachable = if curr_steps%2 == 0 {
-            &mut reachable_even
-        } else {
-            &mut reachable_odd
-        };
-        if reachable.contains(&curr_point) {
-            continue
-        }
-        reachable.insert(curr_point);
-        max_step = max(max_step, curr_steps);
-        get_valid_neighbors(grid, curr_point).into_iter()
-            .for_each(|point| pq.push_back((point, curr_steps+1)));
-    }
-    Values {
-        odd_reachable: reachable_odd.len(),
-        even_reachable: reachable_even.len(),
-        complete_fill: max_step,
-    }
-}
-
-#[derive(Debug, Eq, PartialEq)]
-struct Values {
-    even_reachable: usize,
-    odd_reachable: usize,
-    complete_fill: usize,
+    let n = ((crossings-first)/2) as i64;
+    Ok((a + n*growth + n*(n-1)/2*acceleration) as usize)
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -299,17 +205,11 @@ enum Tile {
     Rock,
 }
 
-impl Parsable for Tile {
-    fn parse(c: char) -> AoCResult<Self> where Self: Sized {
-        match c {
-            'S' => Ok(Self::Start),
-            '.' => Ok(Self::Garden),
-            '#' => Ok(Self::Rock),
-            _ => Err(AoCError::BadInputFormat(
-                format!("Only 'S', '#' and '.' allowed. Found '{}'.", c)))
-        }
-    }
-}
+impl_parsable!(Tile {
+    'S' => Self::Start,
+    '.' => Self::Garden,
+    '#' => Self::Rock,
+});
 
 #[cfg(test)]
 mod test {
@@ -350,20 +250,6 @@ mod test {
         Ok(())
     }
 
-    #[test]
-    fn check_get_values_from_start() -> AoCResult<()> {
-        let input = get_example_input();
-        let grid = Grid::parse(&input)?;
-        let correct = Values {
-            complete_fill: 14,
-            even_reachable: 42,
-            odd_reachable: 39,
-        };
-        assert_eq!(get_values_from_start(&grid, (5, 5)), correct);
-
-        Ok(())
-    }
-
     fn get_own_example_input() -> Vec<String> {
         vec![
             ".......".to_string(),
@@ -382,7 +268,41 @@ mod test {
         let grid = Grid::parse(&input)?;
         let tmp = grid.get_all_positions_of(&Tile::Start);
         let start = tmp[0];
-        assert_eq!(reachable_wrapping(&grid, start, 15), 216);
+        assert_eq!(reachable_wrapping(&grid, start, 15)?, 216);
+        Ok(())
+    }
+
+    #[test]
+    fn check_reachable_wrapping() -> AoCResult<()> {
+        let input = get_own_example_input();
+        let grid = Grid::parse(&input)?;
+        let start = grid.get_all_positions_of(&Tile::Start)[0];
+        let wrapping = grid.wrapping();
+        for steps in [3, 8, 15, 22, 31, 50, 101, 160] {
+            let reachable = wrapping.get_distances(start.cast().expect("Grid is small"),
+                                                   &Neighborhood::VonNeumann, steps,
+                                                   |tile| tile != &Tile::Rock)
+                .values()
+                .filter(|distance| *distance%2 == steps%2)
+                .count();
+            assert_eq!(reachable_wrapping(&grid, start, steps)?, reachable);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn check_reachable_wrapping_not_quadratic() -> AoCResult<()> {
+        let input = vec![
+            "#####".to_string(),
+            "#...#".to_string(),
+            "#.S.#".to_string(),
+            "#...#".to_string(),
+            "#####".to_string(),
+        ];
+        let grid = Grid::parse(&input)?;
+        let start = grid.get_all_positions_of(&Tile::Start)[0];
+        assert!(matches!(reachable_wrapping(&grid, start, 30),
+                         Err(AoCError::NoSolutionFoundError(_))));
         Ok(())
     }
 
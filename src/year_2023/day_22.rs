@@ -1,7 +1,7 @@
 use std::cmp::{max, min};
 use std::collections::HashSet;
 use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Point, Point3D};
+use crate::geometrics::{Point2D, Point3D};
 
 pub fn part_1(input: &[String]) -> AoCResult<String> {
     let mut list = Block::parse_list(input)?;
@@ -93,14 +93,14 @@ fn count_destroyable(list: &Vec<Block>) -> usize {
 
 struct StackArea {
     grid: Vec<Vec<Vec<(usize, usize)>>>,
-    size: Point<usize>,
+    size: Point2D<usize>,
 }
 
 impl StackArea {
     fn from_blocks(list: &[Block]) -> Self {
         let mut res = Self {
             grid: vec![vec![vec![]]],
-            size: (1, 1),
+            size: Point2D::new(1, 1),
         };
         for block in list.iter() {
             res.add_block(block);
@@ -115,24 +115,24 @@ impl StackArea {
     }
 
     fn add(&mut self, point: Point3D<usize>, id: usize) {
-        if point.x >= self.size.0 || point.y >= self.size.1 {
+        if point.x >= self.size.x || point.y >= self.size.y {
             self.resize(point);
         }
         self.grid[point.x][point.y].push((point.z, id));
     }
 
     fn resize(&mut self, point3d: Point3D<usize>) {
-        if point3d.x >= self.size.0 {
-            self.grid.resize(point3d.x+1, vec![vec![]; self.size.1]);
-            self.size.0 = point3d.x+1;
-            assert_eq!(self.size.0, self.grid.len());
+        if point3d.x >= self.size.x {
+            self.grid.resize(point3d.x+1, vec![vec![]; self.size.y]);
+            self.size.x = point3d.x+1;
+            assert_eq!(self.size.x, self.grid.len());
         }
-        if point3d.y >= self.size.1 {
+        if point3d.y >= self.size.y {
             for y_row in self.grid.iter_mut() {
                 y_row.resize(point3d.y+1, vec![]);
             }
-            self.size.1 = point3d.y+1;
-            assert_eq!(self.size.1, self.grid[0].len());
+            self.size.y = point3d.y+1;
+            assert_eq!(self.size.y, self.grid[0].len());
         }
     }
 
@@ -2,7 +2,7 @@ use std::cmp::max;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Direction, Grid, Parsable, Point};
+use crate::geometrics::{impl_parsable, Direction, Grid, Point2D};
 
 pub fn part_1(input: &[String]) -> AoCResult<String> {
     let grid: Grid<Tile> = Grid::parse(input)?;
@@ -30,8 +30,8 @@ impl Grid<Tile> {
             .ok_or_else(|| AoCError::NoSolutionFoundError("No path found".to_string()))
     }
 
-    fn follow(&self, pos: Point<usize>, goal: Point<usize>,
-        visited: &mut HashMap<Point<usize>, usize>, counter: usize, slippery: bool)
+    fn follow(&self, pos: Point2D<usize>, goal: Point2D<usize>,
+        visited: &mut HashMap<Point2D<usize>, usize>, counter: usize, slippery: bool)
         -> Option<usize>
     {
         if counter == 1000 {
@@ -77,7 +77,7 @@ impl Grid<Tile> {
         None
     }
 
-    fn get_start(&self) -> Option<Point<usize>> {
+    fn get_start(&self) -> Option<Point2D<usize>> {
         let starts = self.row_iter(0)?
             .enumerate()
             .filter(|(_, elem)| elem != &&Tile::Wall)
@@ -85,11 +85,11 @@ impl Grid<Tile> {
         if starts.len() > 1 {
             return None
         }
-        starts.first().map(|(x, _)| (*x, 0))
+        starts.first().map(|(x, _)| Point2D::new(*x, 0))
     }
 
-    fn get_end(&self) -> Option<Point<usize>> {
-        let last = self.get_dimension().1-1;
+    fn get_end(&self) -> Option<Point2D<usize>> {
+        let last = self.get_dimension().y-1;
         let starts = self.row_iter(last)?
             .enumerate()
             .filter(|(_, elem)| elem != &&Tile::Wall)
@@ -97,11 +97,11 @@ impl Grid<Tile> {
         if starts.len() > 1 {
             return None
         }
-        starts.first().map(|(x, _)| (*x, last))
+        starts.first().map(|(x, _)| Point2D::new(*x, last))
     }
 }
 /*
-fn get_node_index(node_map: &mut HashMap<Point<usize>, usize>, node: &Point<usize>) -> usize {
+fn get_node_index(node_map: &mut HashMap<Point2D<usize>, usize>, node: &Point2D<usize>) -> usize {
     if let Some(index) = node_map.get(node) {
         *index
     } else {
@@ -112,14 +112,14 @@ fn get_node_index(node_map: &mut HashMap<Point<usize>, usize>, node: &Point<usiz
 }
 
 fn grid_to_graph(grid: &Grid<Tile>, gt: GraphType) 
-    -> AoCResult<(GraphWeighted, HashMap<Point<usize>, usize>)> 
+    -> AoCResult<(GraphWeighted, HashMap<Point2D<usize>, usize>)> 
 {
     let mut graph = GraphWeighted::new(gt);
     let mut map = HashMap::new();
     let size = grid.get_dimension();
-    for x in 0..size.0 {
-        for y in 0..size.1 {
-            let from_point = (x, y);
+    for x in 0..size.x {
+        for y in 0..size.y {
+            let from_point = Point2D::new(x, y);
             let from_node = get_node_index(&mut map, &from_point);
             let tile = grid.get_tile(&from_point).ok_or_else(|| todo!())?;
             match tile {
@@ -180,20 +180,14 @@ impl Tile {
     }
 }
 
-impl Parsable for Tile {
-    fn parse(c: char) -> AoCResult<Self> where Self: Sized {
-        match c {
-            '#' => Ok(Self::Wall),
-            '.' => Ok(Self::Path),
-            '<' => Ok(Self::Slope(Direction::West)),
-            '>' => Ok(Self::Slope(Direction::East)),
-            '^' => Ok(Self::Slope(Direction::North)),
-            'v' => Ok(Self::Slope(Direction::South)),
-            _ => Err(AoCError::BadInputFormat(
-                format!("Parsing Tile failed expected one of '.#<>^v'. Found '{}'", c)))
-        }
-    }
-}
+impl_parsable!(Tile {
+    '#' => Self::Wall,
+    '.' => Self::Path,
+    '<' => Self::Slope(Direction::West),
+    '>' => Self::Slope(Direction::East),
+    '^' => Self::Slope(Direction::North),
+    'v' => Self::Slope(Direction::South),
+});
 
 impl Display for Tile {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
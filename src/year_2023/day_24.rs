@@ -1,5 +1,5 @@
 use crate::errors::{AoCError, AoCResult};
-use crate::geometrics::{Point, Point3D};
+use crate::geometrics::{Point2D, Point3D};
 
 pub fn part_1(input: &[String]) -> AoCResult<String> {
     let points_and_velocity = input_to_points_and_velocity(input)?;
@@ -7,7 +7,7 @@ pub fn part_1(input: &[String]) -> AoCResult<String> {
         .ok_or_else(|| AoCError::NoSolutionFoundError("Creating equation failed".to_string()))?;
     let min = 200000000000000.0;
     let max = 400000000000000.0;
-    let area = ((min, min), (max, max));
+    let area = (Point2D::new(min, min), Point2D::new(max, max));
     let count = count_intersecting_in(&equations, area);
     Ok(count.to_string())
 }
@@ -16,7 +16,7 @@ pub fn part_2(_input: &Vec<String>) -> AoCResult<String> {
     todo!()
 }
 
-fn input_to_points_and_velocity(input: &[String]) -> AoCResult<Vec<(Point<i64>, Point<i64>)>> {
+fn input_to_points_and_velocity(input: &[String]) -> AoCResult<Vec<(Point2D<i64>, Point2D<i64>)>> {
     let points_and_velocity = input.iter()
         .map(|line| parse_line(line))
         .collect::<AoCResult<Vec<_>>>()?;
@@ -25,7 +25,9 @@ fn input_to_points_and_velocity(input: &[String]) -> AoCResult<Vec<(Point<i64>,
         .collect())
 }
 
-fn points_and_velocity_to_equations(points: &[(Point<i64>, Point<i64>)]) -> Option<Vec<Equation>> {
+fn points_and_velocity_to_equations(points: &[(Point2D<i64>, Point2D<i64>)])
+    -> Option<Vec<Equation>>
+{
     points.iter()
         .map(|(p, v)| Equation::from_point_and_velocity(p, v))
         .collect()
@@ -64,25 +66,25 @@ fn parse_point(str: &str) -> AoCResult<Point3D<i64>> {
     })
 }
 
-fn points_3d_to_2d(p0: Point3D<i64>, p1: Point3D<i64>) -> (Point<i64>, Point<i64>) {
-    let p0 = (p0.x, p0.y);
-    let p1 = (p1.x, p1.y);
+fn points_3d_to_2d(p0: Point3D<i64>, p1: Point3D<i64>) -> (Point2D<i64>, Point2D<i64>) {
+    let p0 = Point2D::new(p0.x, p0.y);
+    let p1 = Point2D::new(p1.x, p1.y);
     (p0, p1)
 }
 
-fn count_intersecting_in(list: &[Equation], area: (Point<f64>, Point<f64>)) -> usize {
+fn count_intersecting_in(list: &[Equation], area: (Point2D<f64>, Point2D<f64>)) -> usize {
     let mut count = 0;
     for index0 in 0..list.len() {
         for index1 in index0+1..list.len() {
 //            print!("checking {} and {}...", index0, index1);
             let intersection = list[index0].intersect_after_x(&list[index1]);
             if let Some(point) = intersection {
-                if point.0 >= area.0.0 && point.0 <= area.1.0 &&
-                    point.1 >= area.0.1 && point.1 <= area.1.1 {
+                if point.x >= area.0.x && point.x <= area.1.x &&
+                    point.y >= area.0.y && point.y <= area.1.y {
                     count += 1;
-//                    println!("intersection inside at ({}, {})", point.0, point.1);
+//                    println!("intersection inside at ({}, {})", point.x, point.y);
                 } else {
-//                    println!("intersection outside at ({}, {})", point.0, point.1);
+//                    println!("intersection outside at ({}, {})", point.x, point.y);
                 }
             } else {
 //                println!("no intersection");
@@ -94,14 +96,14 @@ fn count_intersecting_in(list: &[Equation], area: (Point<f64>, Point<f64>)) -> u
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 struct Equation {
-    p_start: Point<i64>,
-    p_velocity: Point<i64>,
+    p_start: Point2D<i64>,
+    p_velocity: Point2D<i64>,
     m: f64,
     t: f64,
 }
 
 impl Equation {
-    fn from_point_and_velocity(p: &Point<i64>, v: &Point<i64>) -> Option<Self> {
+    fn from_point_and_velocity(p: &Point2D<i64>, v: &Point2D<i64>) -> Option<Self> {
         let p1 = Self::point_from_velocity(p, v);
         let (m, t) = Self::m_and_t_from_points(p, &p1)?;
         Some(Self {
@@ -112,35 +114,33 @@ impl Equation {
         })
     }
 
-    fn point_from_velocity(p: &Point<i64>, v: &Point<i64>) -> Point<i64> {
-        (p.0 + v.0, p.1 + v.1)
+    fn point_from_velocity(p: &Point2D<i64>, v: &Point2D<i64>) -> Point2D<i64> {
+        *p + *v
     }
 
-    fn m_and_t_from_points(p0: &Point<i64>, p1: &Point<i64>) -> Option<(f64, f64)> {
+    fn m_and_t_from_points(p0: &Point2D<i64>, p1: &Point2D<i64>) -> Option<(f64, f64)> {
         if p0 == p1 {
             return None
         }
-        let dx = (p0.0-p1.0) as f64;
-        let dy = (p0.1-p1.1) as f64;
+        let diff = *p0 - *p1;
+        let dx = diff.x as f64;
+        let dy = diff.y as f64;
         let m = dy / dx;
-        let t = p0.1 as f64 - m * p0.0 as f64;
+        let t = p0.y as f64 - m * p0.x as f64;
         Some((m, t))
     }
 
-    fn intersect(&self, other: &Self) -> Option<Point<f64>> {
+    fn intersect(&self, other: &Self) -> Option<Point2D<f64>> {
         if self.m == other.m {
             return None
         }
         let x = (self.t-other.t)/(other.m-self.m);
         let y = self.solve(x);
 
-        Some((
-            x,
-            y,
-        ))
+        Some(Point2D::new(x, y))
     }
 
-    fn intersect_after_x(&self, other: &Self) -> Option<Point<f64>> {
+    fn intersect_after_x(&self, other: &Self) -> Option<Point2D<f64>> {
         let intersection = self.intersect(other)?;
         if self.is_in_future(&intersection) && other.is_in_future(&intersection) {
             Some(intersection)
@@ -149,11 +149,11 @@ impl Equation {
         }
     }
 
-    fn is_in_future(&self, p: &Point<f64>) -> bool {
-        let start = (self.p_start.0 as f64, self.p_start.1 as f64);
-        let diff = (p.0 - start.0, p.1 - start.1);
-        if self.p_velocity.0.signum() as f64 == diff.0.signum() &&
-            self.p_velocity.1.signum() as f64 == diff.1.signum() {
+    fn is_in_future(&self, p: &Point2D<f64>) -> bool {
+        let start = Point2D::new(self.p_start.x as f64, self.p_start.y as f64);
+        let diff = *p - start;
+        if self.p_velocity.x.signum() as f64 == diff.x.signum() &&
+            self.p_velocity.y.signum() as f64 == diff.y.signum() {
             true
         } else {
             false
@@ -186,7 +186,7 @@ mod test {
         let points = input_to_points_and_velocity(&input)?;
         let equations = points_and_velocity_to_equations(&points)
             .ok_or_else(|| AoCError::NoSolutionFoundError("Creating Equation failed".to_string()))?;
-        let area = ((7.0, 7.0), (27.0, 27.0));
+        let area = (Point2D::new(7.0, 7.0), Point2D::new(27.0, 27.0));
         assert_eq!(count_intersecting_in(&equations, area), 2);
         Ok(())
     }
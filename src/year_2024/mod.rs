@@ -1,3 +1,5 @@
+use crate::solution::{register_days, Solution};
+
 pub mod lib_2024;
 
 pub mod day_01;
@@ -25,4 +27,11 @@ pub mod day_22;
 pub mod day_23;
 pub mod day_24;
 pub mod day_25;
-*/
\ No newline at end of file
+*/
+
+pub fn solutions() -> Vec<Solution> {
+    register_days!(2024,
+        1 => day_01,
+        2 => day_02,
+    )
+}